@@ -3,12 +3,31 @@
 //! This module contains all the asynchronous methods for the DRV260X haptic driver.
 //! All methods follow the same patterns as the synchronous versions but use async/await.
 
-use crate::ll::{FbBrakeFactor, LoopGain, OperatingMode};
+use crate::ll::{BemfGain, FbBrakeFactor, LoopGain, OperatingMode};
+#[cfg(any(feature = "drv2604l", feature = "drv2605l"))]
+use crate::ll::AutoOpenLoopCnt;
 #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
 use crate::ll::{AthFilter, AthPeakTime, LibrarySelection};
-use crate::{Drv260x, Error, StatusInfo, WaveformEntry};
+use crate::{
+    check_feedback_actuator, config, effects, sequence_timeout_us, time_offset_units, Actuator,
+    CalibrationResult, Config, Control3Fields, ControlConfig, DeviceVariant, Drv260x,
+    Drv260xBuilder, Error, Fault, FeedbackControl, SelfTestReport, Sequence, StatusInfo,
+    ThermalStatus, WaveformEntry, AUTO_CALIBRATION_TIMEOUT_US, LRA_PERIOD_STEP_US,
+    PLAYBACK_POLL_INTERVAL_MS, RESET_TIMEOUT_MS, RESET_TIME_MS, SELF_TEST_STEP_TIMEOUT_US,
+    SEQUENCER_SLOTS,
+};
 #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
-use crate::Effect;
+use crate::{playback_timeout_us, AudioToVibeConfig, Effect};
+#[cfg(feature = "bitflags")]
+use crate::StatusFlags;
+#[cfg(all(feature = "otp", any(feature = "drv2605l", feature = "drv2604l")))]
+use crate::OTP_PROGRAM_TIME_MS;
+#[cfg(any(feature = "drv2604", feature = "drv2604l"))]
+use crate::ram::RAM_SIZE;
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+use embedded_hal_async::delay::DelayNs;
 use embedded_hal_async::i2c::I2c as AsyncI2c;
 
 cfg_if::cfg_if! {
@@ -41,15 +60,35 @@ where
             });
         }
 
-        // Clear standby mode
+        // Cache the detected variant for variant-dependent guards
+        self.variant = Some(DeviceVariant::from_device_id(device_id));
+
+        // Clear standby and select internal trigger mode in a single register write.
+        // All Mode fields are set here, so no read-modify-write is needed.
         self.device
             .mode()
-            .modify_async(|reg| reg.set_standby(false))
+            .write_async(|reg| {
+                reg.set_standby(false);
+                reg.set_mode(OperatingMode::Internal);
+            })
             .await?;
+        self.current_mode = Some(OperatingMode::Internal);
 
-        // Set default mode to internal trigger
-        self.set_mode_async(OperatingMode::Internal).await?;
+        Ok(())
+    }
+
+    /// Initialize the driver and reset the voltage limits to conservative variant defaults
+    /// (async version)
+    pub async fn init_with_variant_defaults_async(&mut self) -> Result<(), Error<E>> {
+        self.init_async().await?;
 
+        let variant = self.variant.unwrap_or(DeviceVariant::Unknown);
+        if let Some(voltage) = variant.default_rated_voltage() {
+            self.set_rated_voltage_async(voltage).await?;
+        }
+        if let Some(voltage) = variant.conservative_overdrive_clamp_voltage() {
+            self.set_overdrive_clamp_voltage_async(voltage).await?;
+        }
         Ok(())
     }
 
@@ -59,7 +98,7 @@ where
         self.init_async().await?;
 
         // Set up for ERM actuator
-        self.set_actuator_type_async(false).await?; // false = ERM mode
+        self.set_actuator_type_async(Actuator::Erm).await?;
 
         // Configure for open-loop operation using low-level access
         self.device
@@ -75,17 +114,100 @@ where
         Ok(())
     }
 
+    /// Initialize the driver for ERM actuator in closed-loop mode (async version)
+    pub async fn init_closed_loop_erm_async(&mut self) -> Result<(), Error<E>> {
+        self.init_async().await?;
+
+        self.set_actuator_type_async(Actuator::Erm).await?;
+
+        self.device
+            .control_3()
+            .modify_async(|reg| {
+                reg.set_erm_open_loop(false); // Closed-loop ERM
+            })
+            .await?;
+
+        self.set_single_effect_async(1).await?;
+
+        Ok(())
+    }
+
+    /// Initialize the driver for LRA actuator in closed-loop mode (async version)
+    pub async fn init_closed_loop_lra_async(&mut self) -> Result<(), Error<E>> {
+        self.init_async().await?;
+
+        self.set_actuator_type_async(Actuator::Lra).await?;
+
+        #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+        self.set_library_async(LibrarySelection::LRA).await?;
+
+        self.device
+            .control_3()
+            .modify_async(|reg| {
+                reg.set_erm_open_loop(false);
+                reg.set_lra_open_loop(false); // Closed-loop LRA (auto-resonance)
+            })
+            .await?;
+
+        self.set_single_effect_async(1).await?;
+
+        Ok(())
+    }
+
+    /// Apply an actuator configuration (async version)
+    pub async fn configure_async(&mut self, config: &Config) -> Result<(), Error<E>> {
+        self.set_actuator_type_async(config.actuator).await?;
+
+        if let Some(voltage) = config.rated_voltage {
+            self.set_rated_voltage_async(voltage).await?;
+        }
+        if let Some(voltage) = config.overdrive_clamp_voltage {
+            self.set_overdrive_clamp_voltage_async(voltage).await?;
+        }
+        if let Some(slots) = config.sequence {
+            self.write_sequencer_slots_async(&slots).await?;
+        }
+
+        self.set_mode_async(config.mode).await
+    }
+
     /// Get comprehensive device status information (async version)
     pub async fn get_status_async(&mut self) -> Result<StatusInfo, Error<E>> {
         let status = self.device.status().read_async().await?;
-        Ok(StatusInfo {
-            overcurrent_detected: status.oc_detect(),
-            overtemperature_detected: status.over_temp(),
-            feedback_status: status.fb_sts(),
-            diagnostic_result: status.diag_result(),
-            illegal_address: status.illegal_addr(),
-            device_id: status.device_id(),
-        })
+        Ok(StatusInfo::from(status))
+    }
+
+    /// Read the status register as typed flags (async version)
+    #[cfg(feature = "bitflags")]
+    pub async fn status_flags_async(&mut self) -> Result<StatusFlags, Error<E>> {
+        Ok(self.get_status_async().await?.flags())
+    }
+
+    /// Check for overcurrent or overtemperature faults (async version)
+    pub async fn check_faults_async(&mut self) -> Result<Option<Fault>, Error<E>> {
+        Ok(self.get_status_async().await?.fault())
+    }
+
+    /// Acknowledge and clear latched fault flags (async version)
+    pub async fn clear_faults_async(&mut self) -> Result<(), Error<E>> {
+        self.device.status().read_async().await?;
+        Ok(())
+    }
+
+    /// Check whether the host accessed an invalid register address (async version)
+    pub async fn check_illegal_address_async(&mut self) -> Result<bool, Error<E>> {
+        Ok(self.get_status_async().await?.illegal_address)
+    }
+
+    /// Get the thermal state of the device (async version)
+    pub async fn thermal_status_async(&mut self) -> Result<ThermalStatus, Error<E>> {
+        Ok(self.get_status_async().await?.thermal_status())
+    }
+
+    /// Detect the connected device variant from the status register (async version)
+    pub async fn detect_variant_async(&mut self) -> Result<DeviceVariant, Error<E>> {
+        let status = self.device.status().read_async().await?;
+        Ok(DeviceVariant::from_device_id(status.device_id()))
     }
 
     /// Set the operating mode (async version)
@@ -98,6 +220,16 @@ where
         Ok(())
     }
 
+    /// Set the operating mode only if it differs from the cached mode (async version)
+    pub async fn ensure_mode_async(&mut self, mode: OperatingMode) -> Result<bool, Error<E>> {
+        if self.current_mode == Some(mode) {
+            return Ok(false);
+        }
+
+        self.set_mode_async(mode).await?;
+        Ok(true)
+    }
+
     /// Get the current operating mode (async version)
     pub async fn get_mode_async(&mut self) -> Result<OperatingMode, Error<E>> {
         let mode_reg = self.device.mode().read_async().await?;
@@ -113,6 +245,26 @@ where
         Ok(())
     }
 
+    /// Enter standby, remembering the current operating mode (async version)
+    pub async fn enter_standby_async(&mut self) -> Result<(), Error<E>> {
+        let mode = match self.current_mode {
+            Some(mode) => mode,
+            None => self.get_mode_async().await?,
+        };
+        self.set_standby_async(true).await?;
+        self.standby_mode = Some(mode);
+        Ok(())
+    }
+
+    /// Exit standby and restore the mode cached by `enter_standby_async` (async version)
+    pub async fn exit_standby_async(&mut self) -> Result<(), Error<E>> {
+        self.set_standby_async(false).await?;
+        if let Some(mode) = self.standby_mode.take() {
+            self.set_mode_async(mode).await?;
+        }
+        Ok(())
+    }
+
     /// Perform device reset (async version)
     pub async fn reset_async(&mut self) -> Result<(), Error<E>> {
         self.device
@@ -122,9 +274,39 @@ where
 
         // Clear cached state after reset
         self.current_mode = None;
+        self.variant = None;
+        self.standby_mode = None;
         Ok(())
     }
 
+    /// Reset the device and wait until it responds again (async version)
+    pub async fn reset_and_wait_async(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<E>> {
+        self.reset_async().await?;
+
+        let mut elapsed_ms = 0;
+        loop {
+            delay.delay_ms(RESET_TIME_MS).await;
+            elapsed_ms += RESET_TIME_MS;
+
+            // The device may NACK while it is still coming out of reset
+            let reset_done = matches!(
+                self.device.mode().read_async().await,
+                Ok(mode) if !mode.dev_reset()
+            );
+            if reset_done {
+                if let Ok(status) = self.device.status().read_async().await {
+                    if status.device_id() == EXPECTED_DEVICE_ID {
+                        return Ok(());
+                    }
+                }
+            }
+
+            if elapsed_ms >= RESET_TIMEOUT_MS {
+                return Err(Error::Timeout);
+            }
+        }
+    }
+
     /// Set high-impedance state (async version)
     pub async fn set_high_impedance_async(&mut self, hi_z: bool) -> Result<(), Error<E>> {
         self.device
@@ -134,6 +316,12 @@ where
         Ok(())
     }
 
+    /// Get high-impedance state (async version)
+    pub async fn get_high_impedance_async(&mut self) -> Result<bool, Error<E>> {
+        let reg = self.device.library_selection().read_async().await?;
+        Ok(reg.hi_z())
+    }
+
     /// Set a single waveform entry in the sequencer (async version)
     pub async fn set_waveform_entry_async(
         &mut self,
@@ -164,17 +352,57 @@ where
             return Err(Error::InvalidWaveform);
         }
 
-        // Set provided entries
-        for (i, &entry) in entries.iter().enumerate() {
-            self.set_waveform_entry_async(i as u8, entry).await?;
-        }
+        let mut slots = [WaveformEntry::stop(); SEQUENCER_SLOTS];
+        slots[..entries.len()].copy_from_slice(entries);
+        self.write_sequencer_slots_async(&slots).await
+    }
 
-        // Clear remaining entries if fewer than 8 provided
-        for i in entries.len()..8 {
-            self.set_waveform_entry_async(i as u8, WaveformEntry::stop())
-                .await?;
-        }
+    /// Set multiple waveform entries (up to 8 entries), validating the effect IDs first (async version)
+    pub async fn set_waveform_sequence_checked_async(
+        &mut self,
+        entries: &[WaveformEntry],
+    ) -> Result<(), Error<E>> {
+        effects::validate_sequence(entries)?;
+        self.set_waveform_sequence_async(entries).await
+    }
+
+    /// Set waveform entries up to the first stop entry, padding the rest (async version)
+    pub async fn set_waveform_sequence_until_stop_async(
+        &mut self,
+        entries: &[WaveformEntry],
+    ) -> Result<(), Error<E>> {
+        let len = effects::playable_len(entries);
+        self.set_waveform_sequence_async(&entries[..len]).await
+    }
+
+    /// Read back all 8 waveform sequencer slots (async version)
+    pub async fn read_waveform_sequence_async(
+        &mut self,
+        out: &mut [WaveformEntry; SEQUENCER_SLOTS],
+    ) -> Result<(), Error<E>> {
+        let block = self.device.waveform_sequencer_block().read_async().await?;
+        effects::unpack_slots(block.slots(), out);
+        Ok(())
+    }
+
+    /// Load a fixed-capacity sequence into the sequencer (async version)
+    pub async fn set_sequence_async<const N: usize>(
+        &mut self,
+        sequence: &Sequence<N>,
+    ) -> Result<(), Error<E>> {
+        self.write_sequencer_slots_async(&sequence.to_slots()).await
+    }
 
+    /// Write all 8 sequencer slots in a single I2C transaction (async version)
+    async fn write_sequencer_slots_async(
+        &mut self,
+        slots: &[WaveformEntry; SEQUENCER_SLOTS],
+    ) -> Result<(), Error<E>> {
+        let raw = effects::pack_slots(slots);
+        self.device
+            .waveform_sequencer_block()
+            .write_async(|reg| reg.set_slots(raw))
+            .await?;
         Ok(())
     }
 
@@ -184,18 +412,124 @@ where
         self.set_waveform_sequence_async(&sequence).await
     }
 
+    /// Play a waveform sequence of arbitrary length (async version)
+    ///
+    /// Chunks are bounded like [`Drv260x::play_sequence`]: playback is stopped and
+    /// `Error::Timeout` returned if a chunk runs far beyond its estimated duration.
+    pub async fn play_sequence_async(
+        &mut self,
+        entries: &[WaveformEntry],
+        delay: &mut impl DelayNs,
+    ) -> Result<(), Error<E>> {
+        // Only play up to the first stop entry
+        let end = effects::playable_len(entries);
+
+        for chunk in entries[..end].chunks(SEQUENCER_SLOTS) {
+            self.set_waveform_sequence_async(chunk).await?;
+            self.go_async().await?;
+
+            let played = self
+                .wait_until_done_async(delay, sequence_timeout_us(chunk))
+                .await;
+            if matches!(played, Err(Error::Timeout)) {
+                self.stop_async().await?;
+            }
+            played?;
+        }
+
+        Ok(())
+    }
+
+    /// Play a waveform sequence `count` times back-to-back (async version)
+    pub async fn play_repeating_async(
+        &mut self,
+        entries: &[WaveformEntry],
+        count: u8,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), Error<E>> {
+        for _ in 0..count.max(1) {
+            self.play_sequence_async(entries, delay).await?;
+        }
+        Ok(())
+    }
+
     /// Trigger playback (set GO bit) (async version)
     pub async fn go_async(&mut self) -> Result<(), Error<E>> {
         self.device.go().write_async(|reg| reg.set_go(true)).await?;
         Ok(())
     }
 
+    /// Trigger playback after checking that the device is not in standby (async version)
+    pub async fn go_checked_async(&mut self) -> Result<(), Error<E>> {
+        if self.device.mode().read_async().await?.standby() {
+            return Err(Error::NotReady);
+        }
+        self.go_async().await
+    }
+
+    /// Trigger playback and verify that the GO bit latched (async version)
+    pub async fn trigger_verified_async(&mut self) -> Result<(), Error<E>> {
+        self.go_async().await?;
+        if !self.is_active_async().await? {
+            return Err(Error::NotReady);
+        }
+        Ok(())
+    }
+
+    /// Wait until playback completes (GO bit clears) (async version)
+    pub async fn wait_until_done_async(
+        &mut self,
+        delay: &mut impl DelayNs,
+        timeout_us: u32,
+    ) -> Result<(), Error<E>> {
+        let mut elapsed_us: u32 = 0;
+        while self.is_active_async().await? {
+            if elapsed_us >= timeout_us {
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(PLAYBACK_POLL_INTERVAL_MS).await;
+            elapsed_us = elapsed_us.saturating_add(PLAYBACK_POLL_INTERVAL_MS * 1000);
+        }
+        Ok(())
+    }
+
+    /// Trigger playback and wait until it naturally completes (async version)
+    pub async fn go_and_wait_async(
+        &mut self,
+        delay: &mut impl DelayNs,
+        timeout_us: u32,
+    ) -> Result<(), Error<E>> {
+        self.go_async().await?;
+        self.wait_until_done_async(delay, timeout_us).await
+    }
+
+    /// Arm the device to play `entry` from the external IN/TRIG pin (async version)
+    pub async fn arm_external_trigger_async(
+        &mut self,
+        entry: WaveformEntry,
+        level_mode: bool,
+    ) -> Result<(), Error<E>> {
+        if entry.is_stop() {
+            return Err(Error::InvalidWaveform);
+        }
+        self.set_waveform_sequence_async(&[entry, WaveformEntry::stop()])
+            .await?;
+
+        let mode = if level_mode {
+            OperatingMode::ExternalLevel
+        } else {
+            OperatingMode::ExternalEdge
+        };
+        self.set_mode_async(mode).await
+    }
+
     /// Stop playback (clear GO bit) (async version)
     pub async fn stop_async(&mut self) -> Result<(), Error<E>> {
         self.device
             .go()
             .write_async(|reg| reg.set_go(false))
             .await?;
+        self.stop_pending = false;
         Ok(())
     }
 
@@ -205,6 +539,12 @@ where
         Ok(go_reg.go())
     }
 
+    /// Read the raw GO register byte without interpreting it (async version)
+    pub async fn read_go_raw_async(&mut self) -> Result<u8, Error<E>> {
+        let go_reg = self.device.go().read_async().await?;
+        Ok(<[u8; 1]>::from(go_reg)[0])
+    }
+
     /// Set real-time playback input value (async version)
     pub async fn set_rtp_input_async(&mut self, value: u8) -> Result<(), Error<E>> {
         self.device
@@ -214,6 +554,163 @@ where
         Ok(())
     }
 
+    /// Set real-time playback input as a signed value (async version)
+    pub async fn set_rtp_input_signed_async(&mut self, value: i8) -> Result<(), Error<E>> {
+        let control3 = self.device.control_3().read_async().await?;
+        if control3.data_format_rtp() {
+            return Err(Error::InvalidConfig("RTP data format is unsigned"));
+        }
+
+        self.device
+            .real_time_playback_input()
+            .write_async(|reg| reg.set_rtp_input(value as u8))
+            .await?;
+        Ok(())
+    }
+
+    /// Get the current real-time playback input value (async version)
+    pub async fn get_rtp_input_async(&mut self) -> Result<u8, Error<E>> {
+        let reg = self.device.real_time_playback_input().read_async().await?;
+        Ok(reg.rtp_input())
+    }
+
+    /// Get the current real-time playback input as a signed value (async version)
+    pub async fn get_rtp_input_signed_async(&mut self) -> Result<i8, Error<E>> {
+        let control3 = self.device.control_3().read_async().await?;
+        if control3.data_format_rtp() {
+            return Err(Error::InvalidConfig("RTP data format is unsigned"));
+        }
+
+        Ok(self.get_rtp_input_async().await? as i8)
+    }
+
+    /// Linearly ramp the real-time playback input from one value to another (async version)
+    pub async fn ramp_rtp_async(
+        &mut self,
+        from: u8,
+        to: u8,
+        steps: u16,
+        step_delay: &mut impl DelayNs,
+        delay_us: u32,
+    ) -> Result<(), Error<E>> {
+        self.ensure_mode_async(OperatingMode::Playback).await?;
+
+        if steps == 0 {
+            return self.set_rtp_input_async(to).await;
+        }
+
+        self.set_rtp_input_async(from).await?;
+        let span = i32::from(to) - i32::from(from);
+        for step in 1..=i32::from(steps) {
+            step_delay.delay_us(delay_us).await;
+            let value = i32::from(from) + span * step / i32::from(steps);
+            self.set_rtp_input_async(value as u8).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Stream real-time playback samples at a fixed sample period (async version)
+    ///
+    /// Awaits `delay` between samples so other tasks can run while streaming. Like
+    /// [`Drv260x::stream_rtp`], GO is not used in RTP mode: the stream ends by writing the
+    /// no-drive value for the configured DATA_FORMAT_RTP (0x00 signed, 0x80 unsigned).
+    ///
+    /// # Cancellation
+    ///
+    /// Dropping the future between samples leaves the device in RTP mode driving the last
+    /// written sample. Because a dropped future cannot issue the final bus write, the driver
+    /// records the interruption instead (see [`Drv260x::is_stop_pending`]): the next
+    /// `stream_rtp_async` call silences the RTP input before starting, and
+    /// [`clear_pending_stop_async`](Self::clear_pending_stop_async) silences it immediately.
+    pub async fn stream_rtp_async(
+        &mut self,
+        samples: impl IntoIterator<Item = u8>,
+        delay: &mut impl DelayNs,
+        sample_period_us: u32,
+    ) -> Result<(), Error<E>> {
+        self.clear_pending_stop_async().await?;
+
+        self.ensure_mode_async(OperatingMode::Playback).await?;
+        self.stop_pending = true;
+
+        for sample in samples {
+            self.set_rtp_input_async(sample).await?;
+            delay.delay_us(sample_period_us).await;
+        }
+
+        let idle = self.rtp_idle_input_async().await?;
+        self.set_rtp_input_async(idle).await?;
+        self.stop_pending = false;
+        Ok(())
+    }
+
+    /// RTP input value that produces no drive in the configured DATA_FORMAT_RTP
+    async fn rtp_idle_input_async(&mut self) -> Result<u8, Error<E>> {
+        let unsigned = self
+            .device
+            .control_3()
+            .read_async()
+            .await?
+            .data_format_rtp();
+        Ok(if unsigned { 0x80 } else { 0x00 })
+    }
+
+    /// Silence playback left running by a dropped future
+    ///
+    /// Does nothing unless [`Drv260x::is_stop_pending`] is set. In RTP mode, where clearing
+    /// GO has no effect, it writes the no-drive RTP input for the configured DATA_FORMAT_RTP;
+    /// in the other modes it clears GO.
+    pub async fn clear_pending_stop_async(&mut self) -> Result<(), Error<E>> {
+        if self.stop_pending {
+            if self.current_mode == Some(OperatingMode::Playback) {
+                let idle = self.rtp_idle_input_async().await?;
+                self.set_rtp_input_async(idle).await?;
+            } else {
+                self.stop_async().await?;
+            }
+            self.stop_pending = false;
+        }
+        Ok(())
+    }
+
+    /// Trigger playback and return a guard that makes a best-effort stop if dropped early
+    ///
+    /// Await [`PlaybackGuard::wait_async`] to let playback finish. If the surrounding future
+    /// is cancelled first (e.g. an Embassy `select` with a timeout), `Drop` cannot await, so it
+    /// polls the stop write once. Buses that complete a transfer without suspending (blocking
+    /// adapters wrapped as async) stop playback right there; otherwise the driver is marked
+    /// stop-pending (see [`Drv260x::is_stop_pending`]) and the next guarded playback, or an
+    /// explicit [`clear_pending_stop_async`](Self::clear_pending_stop_async), stops it first.
+    pub async fn play_guarded_async(&mut self) -> Result<PlaybackGuard<'_, I2C>, Error<E>> {
+        self.clear_pending_stop_async().await?;
+
+        self.stop_pending = true;
+        self.go_async().await?;
+        Ok(PlaybackGuard {
+            driver: self,
+            finished: false,
+        })
+    }
+
+    /// Select the RTP data format (signed or unsigned) (async version)
+    pub async fn set_rtp_data_format_async(&mut self, signed: bool) -> Result<(), Error<E>> {
+        self.device
+            .control_3()
+            .modify_async(|reg| reg.set_data_format_rtp(!signed))
+            .await?;
+        Ok(())
+    }
+
+    /// Select the input type used in PWM/analog mode (async version)
+    pub async fn set_pwm_analog_input_async(&mut self, analog: bool) -> Result<(), Error<E>> {
+        self.device
+            .control_3()
+            .modify_async(|reg| reg.set_n_pwm_analog(analog))
+            .await?;
+        Ok(())
+    }
+
     /// Set rated voltage for calibration (async version)
     pub async fn set_rated_voltage_async(&mut self, voltage: u8) -> Result<(), Error<E>> {
         self.device
@@ -232,68 +729,343 @@ where
         Ok(())
     }
 
-    /// Configure feedback control for ERM/LRA selection (async version)
-    pub async fn set_actuator_type_async(&mut self, is_lra: bool) -> Result<(), Error<E>> {
+    /// Get the raw RATED_VOLTAGE register value (async version)
+    pub async fn get_rated_voltage_async(&mut self) -> Result<u8, Error<E>> {
+        let reg = self.device.rated_voltage().read_async().await?;
+        Ok(reg.rated_voltage())
+    }
+
+    /// Get the raw OD_CLAMP register value (async version)
+    pub async fn get_overdrive_clamp_voltage_async(&mut self) -> Result<u8, Error<E>> {
+        let reg = self.device.overdrive_clamp_voltage().read_async().await?;
+        Ok(reg.od_clamp())
+    }
+
+    /// Get the rated voltage in volts (async version)
+    pub async fn get_rated_voltage_volts_async(&mut self) -> Result<f32, Error<E>> {
+        let actuator = self.get_actuator_type_async().await?;
+        let value = self.get_rated_voltage_async().await?;
+        Ok(config::rated_voltage_to_volts(actuator, value))
+    }
+
+    /// Get the overdrive clamp voltage in volts (async version)
+    pub async fn get_overdrive_clamp_voltage_volts_async(&mut self) -> Result<f32, Error<E>> {
+        let value = self.get_overdrive_clamp_voltage_async().await?;
+        Ok(config::overdrive_clamp_to_volts(value))
+    }
+
+    /// Select the actuator type (async version)
+    pub async fn set_actuator_type_async(&mut self, actuator: Actuator) -> Result<(), Error<E>> {
         self.device
             .feedback_control()
-            .modify_async(|reg| reg.set_n_erm_lra(is_lra))
+            .modify_async(|reg| reg.set_n_erm_lra(actuator == Actuator::Lra))
             .await?;
         Ok(())
     }
 
-    /// Set feedback control parameters (async version)
-    pub async fn set_feedback_control_async(
-        &mut self,
-        loop_gain: LoopGain,
-        brake_factor: FbBrakeFactor,
-        bemf_gain: u8,
-    ) -> Result<(), Error<E>> {
+    /// Select the actuator type from a bool (async version)
+    #[deprecated(note = "use `set_actuator_type_async` with an `Actuator`")]
+    pub async fn set_actuator_type_bool_async(&mut self, is_lra: bool) -> Result<(), Error<E>> {
         self.device
             .feedback_control()
-            .modify_async(|reg| {
-                reg.set_loop_gain(loop_gain);
-                reg.set_fb_brake_factor(brake_factor);
-                reg.set_bemf_gain(bemf_gain & 0x3); // 2-bit field
-            })
+            .modify_async(|reg| reg.set_n_erm_lra(is_lra))
             .await?;
         Ok(())
     }
 
-    /// Set overdrive time offset for library waveforms (async version)
-    pub async fn set_overdrive_time_offset_async(&mut self, offset: i8) -> Result<(), Error<E>> {
-        self.device
-            .overdrive_time_offset()
-            .write_async(|reg| reg.set_odt(offset as u8))
-            .await?;
-        Ok(())
+    /// Get the actuator type selected in the feedback control register (async version)
+    pub async fn get_actuator_type_async(&mut self) -> Result<Actuator, Error<E>> {
+        let reg = self.device.feedback_control().read_async().await?;
+        Ok(if reg.n_erm_lra() {
+            Actuator::Lra
+        } else {
+            Actuator::Erm
+        })
     }
 
-    /// Set positive sustain time offset for library waveforms (async version)
-    pub async fn set_sustain_time_offset_positive_async(
+    /// Select open-loop or closed-loop operation for the given actuator type (async version)
+    pub async fn set_loop_mode_async(
         &mut self,
-        offset: i8,
+        actuator: Actuator,
+        open_loop: bool,
     ) -> Result<(), Error<E>> {
         self.device
-            .sustain_time_offset_pos()
-            .write_async(|reg| reg.set_spt(offset as u8))
+            .control_3()
+            .modify_async(|reg| match actuator {
+                Actuator::Erm => reg.set_erm_open_loop(open_loop),
+                Actuator::Lra => reg.set_lra_open_loop(open_loop),
+            })
             .await?;
         Ok(())
     }
 
-    /// Set negative sustain time offset for library waveforms (async version)
-    pub async fn set_sustain_time_offset_negative_async(
+    /// Check whether open-loop operation is selected for the given actuator type (async version)
+    pub async fn get_loop_mode_async(&mut self, actuator: Actuator) -> Result<bool, Error<E>> {
+        let control3 = self.device.control_3().read_async().await?;
+        Ok(match actuator {
+            Actuator::Erm => control3.erm_open_loop(),
+            Actuator::Lra => control3.lra_open_loop(),
+        })
+    }
+
+    /// Select the LRA drive mode (once or twice per resonance cycle) (async version)
+    pub async fn set_lra_drive_mode_async(
         &mut self,
-        offset: i8,
+        twice_per_cycle: bool,
     ) -> Result<(), Error<E>> {
         self.device
-            .sustain_time_offset_neg()
-            .write_async(|reg| reg.set_snt(offset as u8))
+            .control_3()
+            .modify_async(|reg| reg.set_lra_drive_mode(twice_per_cycle))
             .await?;
         Ok(())
     }
 
-    /// Set brake time offset for library waveforms (async version)
-    pub async fn set_brake_time_offset_async(&mut self, offset: i8) -> Result<(), Error<E>> {
+    /// Configure the LRA automatic transition from auto-resonance to open loop (async version)
+    #[cfg(any(feature = "drv2604l", feature = "drv2605l"))]
+    pub async fn set_lra_open_loop_resonance_async(
+        &mut self,
+        attempts: Option<AutoOpenLoopCnt>,
+    ) -> Result<(), Error<E>> {
+        self.device
+            .control_5()
+            .modify_async(|reg| {
+                reg.set_lra_auto_open_loop(attempts.is_some());
+                if let Some(attempts) = attempts {
+                    reg.set_auto_ol_cnt(attempts);
+                }
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get the LRA automatic open-loop fallback setting (async version)
+    #[cfg(any(feature = "drv2604l", feature = "drv2605l"))]
+    pub async fn get_lra_open_loop_resonance_async(
+        &mut self,
+    ) -> Result<Option<AutoOpenLoopCnt>, Error<E>> {
+        let control5 = self.device.control_5().read_async().await?;
+        Ok(control5
+            .lra_auto_open_loop()
+            .then(|| control5.auto_ol_cnt()))
+    }
+
+    /// Enable or disable the startup boost (async version)
+    pub async fn set_startup_boost_async(&mut self, enabled: bool) -> Result<(), Error<E>> {
+        self.device
+            .control_1()
+            .modify_async(|reg| reg.set_startup_boost(enabled))
+            .await?;
+        Ok(())
+    }
+
+    /// Check whether the startup boost is enabled (async version)
+    pub async fn get_startup_boost_async(&mut self) -> Result<bool, Error<E>> {
+        let control1 = self.device.control_1().read_async().await?;
+        Ok(control1.startup_boost())
+    }
+
+    /// Enable or disable supply compensation (async version)
+    pub async fn set_supply_compensation_async(&mut self, enabled: bool) -> Result<(), Error<E>> {
+        self.device
+            .control_3()
+            .modify_async(|reg| reg.set_supply_comp_dis(!enabled))
+            .await?;
+        Ok(())
+    }
+
+    /// Check whether supply compensation is enabled (async version)
+    pub async fn get_supply_compensation_async(&mut self) -> Result<bool, Error<E>> {
+        let control3 = self.device.control_3().read_async().await?;
+        Ok(!control3.supply_comp_dis())
+    }
+
+    /// Set several Control3 options in one read-modify-write cycle (async version)
+    pub async fn set_control3_fields_async(
+        &mut self,
+        fields: Control3Fields,
+    ) -> Result<(), Error<E>> {
+        self.device
+            .control_3()
+            .modify_async(|reg| {
+                reg.set_erm_open_loop(fields.erm_open_loop);
+                reg.set_lra_open_loop(fields.lra_open_loop);
+                reg.set_data_format_rtp(!fields.rtp_signed);
+                reg.set_n_pwm_analog(fields.analog_input);
+                reg.set_supply_comp_dis(!fields.supply_compensation);
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Read Control1-Control4 into a typed snapshot (async version)
+    pub async fn get_control_config_async(&mut self) -> Result<ControlConfig, Error<E>> {
+        let control1 = self.device.control_1().read_async().await?;
+        let control2 = self.device.control_2().read_async().await?;
+        let control3 = self.device.control_3().read_async().await?;
+        let control4 = self.device.control_4().read_async().await?;
+
+        Ok(ControlConfig {
+            drive_time: control1.drive_time(),
+            ac_couple: control1.ac_couple(),
+            startup_boost: control1.startup_boost(),
+            idiss_time: control2.idiss_time(),
+            blanking_time: control2.blanking_time(),
+            sample_time: control2.sample_time(),
+            brake_stabilizer: control2.brake_stabilizer(),
+            bidirectional_input: control2.bidir_input(),
+            lra_open_loop: control3.lra_open_loop(),
+            analog_input: control3.n_pwm_analog(),
+            lra_drive_twice_per_cycle: control3.lra_drive_mode(),
+            rtp_signed: !control3.data_format_rtp(),
+            supply_compensation: !control3.supply_comp_dis(),
+            erm_open_loop: control3.erm_open_loop(),
+            noise_gate: control3.ng_thresh(),
+            otp_programmed: control4.otp_status(),
+            auto_cal_time: control4.auto_cal_time(),
+            zero_cross_time: control4.zc_det_time(),
+        })
+    }
+
+    /// Set feedback control parameters (async version)
+    pub async fn set_feedback_control_async(
+        &mut self,
+        loop_gain: LoopGain,
+        brake_factor: FbBrakeFactor,
+        bemf_gain: u8,
+    ) -> Result<(), Error<E>> {
+        self.set_feedback_control_typed_async(
+            loop_gain,
+            brake_factor,
+            BemfGain::from(bemf_gain & 0x3),
+        )
+        .await
+    }
+
+    /// Set feedback control parameters with a typed BEMF gain (async version)
+    pub async fn set_feedback_control_typed_async(
+        &mut self,
+        loop_gain: LoopGain,
+        brake_factor: FbBrakeFactor,
+        bemf_gain: BemfGain,
+    ) -> Result<(), Error<E>> {
+        self.device
+            .feedback_control()
+            .modify_async(|reg| {
+                reg.set_loop_gain(loop_gain);
+                reg.set_fb_brake_factor(brake_factor);
+                reg.set_bemf_gain(bemf_gain);
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Set feedback control parameters with a raw BEMF gain value (async version)
+    pub async fn set_feedback_control_raw_async(
+        &mut self,
+        loop_gain: LoopGain,
+        brake_factor: FbBrakeFactor,
+        bemf_gain: u8,
+    ) -> Result<(), Error<E>> {
+        if bemf_gain > 0x3 {
+            return Err(Error::InvalidConfig("BEMF gain exceeds 2 bits"));
+        }
+        self.set_feedback_control_typed_async(loop_gain, brake_factor, BemfGain::from(bemf_gain))
+            .await
+    }
+
+    /// Set feedback control parameters tuned for `actuator` (async version)
+    pub async fn set_feedback_control_checked_async(
+        &mut self,
+        actuator: Actuator,
+        loop_gain: LoopGain,
+        brake_factor: FbBrakeFactor,
+        bemf_gain: BemfGain,
+    ) -> Result<(), Error<E>> {
+        let feedback = self.device.feedback_control().read_async().await?;
+        check_feedback_actuator(feedback.n_erm_lra(), actuator)?;
+
+        self.device
+            .feedback_control()
+            .write_async(|reg| {
+                *reg = feedback;
+                reg.set_loop_gain(loop_gain);
+                reg.set_fb_brake_factor(brake_factor);
+                reg.set_bemf_gain(bemf_gain);
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Read back feedback control parameters (async version)
+    pub async fn get_feedback_control_async(&mut self) -> Result<FeedbackControl, Error<E>> {
+        let reg = self.device.feedback_control().read_async().await?;
+        Ok(FeedbackControl {
+            loop_gain: reg.loop_gain(),
+            brake_factor: reg.fb_brake_factor(),
+            bemf_gain: reg.bemf_gain(),
+            actuator: if reg.n_erm_lra() {
+                Actuator::Lra
+            } else {
+                Actuator::Erm
+            },
+        })
+    }
+
+    /// Get the measured LRA resonance frequency in Hz (async version)
+    pub async fn get_lra_frequency_hz_async(&mut self) -> Result<f32, Error<E>> {
+        let period = self
+            .device
+            .lra_resonance_period()
+            .read_async()
+            .await?
+            .lra_period();
+        if period == 0 {
+            return Err(Error::NotReady);
+        }
+        Ok(1_000_000.0 / (f32::from(period) * LRA_PERIOD_STEP_US))
+    }
+
+    /// Check whether the closed-loop feedback is tracking the actuator (async version)
+    #[cfg(any(feature = "drv2604", feature = "drv2605"))]
+    pub async fn is_feedback_locked_async(&mut self) -> Result<bool, Error<E>> {
+        Ok(!self.get_status_async().await?.feedback_status)
+    }
+
+    /// Set overdrive time offset for library waveforms (async version)
+    pub async fn set_overdrive_time_offset_async(&mut self, offset: i8) -> Result<(), Error<E>> {
+        self.device
+            .overdrive_time_offset()
+            .write_async(|reg| reg.set_odt(offset as u8))
+            .await?;
+        Ok(())
+    }
+
+    /// Set positive sustain time offset for library waveforms (async version)
+    pub async fn set_sustain_time_offset_positive_async(
+        &mut self,
+        offset: i8,
+    ) -> Result<(), Error<E>> {
+        self.device
+            .sustain_time_offset_pos()
+            .write_async(|reg| reg.set_spt(offset as u8))
+            .await?;
+        Ok(())
+    }
+
+    /// Set negative sustain time offset for library waveforms (async version)
+    pub async fn set_sustain_time_offset_negative_async(
+        &mut self,
+        offset: i8,
+    ) -> Result<(), Error<E>> {
+        self.device
+            .sustain_time_offset_neg()
+            .write_async(|reg| reg.set_snt(offset as u8))
+            .await?;
+        Ok(())
+    }
+
+    /// Set brake time offset for library waveforms (async version)
+    pub async fn set_brake_time_offset_async(&mut self, offset: i8) -> Result<(), Error<E>> {
         self.device
             .brake_time_offset()
             .write_async(|reg| reg.set_brt(offset as u8))
@@ -301,6 +1073,73 @@ where
         Ok(())
     }
 
+    /// Set the overdrive, sustain and brake time offsets in one transaction (async version)
+    pub async fn set_timing_offsets_async(
+        &mut self,
+        overdrive: i8,
+        sustain_pos: i8,
+        sustain_neg: i8,
+        brake: i8,
+    ) -> Result<(), Error<E>> {
+        let raw = u32::from_le_bytes([
+            overdrive as u8,
+            sustain_pos as u8,
+            sustain_neg as u8,
+            brake as u8,
+        ]);
+        self.device
+            .time_offset_block()
+            .write_async(|reg| reg.set_offsets(raw))
+            .await?;
+        Ok(())
+    }
+
+    /// Set overdrive time offset in milliseconds (async version)
+    pub async fn set_overdrive_time_offset_ms_async(
+        &mut self,
+        ms: i16,
+        playback_interval_us: u16,
+    ) -> Result<(), Error<E>> {
+        self.set_overdrive_time_offset_async(time_offset_units(ms, playback_interval_us))
+            .await
+    }
+
+    /// Set positive sustain time offset in milliseconds (async version)
+    pub async fn set_sustain_time_offset_positive_ms_async(
+        &mut self,
+        ms: i16,
+        playback_interval_us: u16,
+    ) -> Result<(), Error<E>> {
+        self.set_sustain_time_offset_positive_async(time_offset_units(ms, playback_interval_us))
+            .await
+    }
+
+    /// Set negative sustain time offset in milliseconds (async version)
+    pub async fn set_sustain_time_offset_negative_ms_async(
+        &mut self,
+        ms: i16,
+        playback_interval_us: u16,
+    ) -> Result<(), Error<E>> {
+        self.set_sustain_time_offset_negative_async(time_offset_units(ms, playback_interval_us))
+            .await
+    }
+
+    /// Set brake time offset in milliseconds (async version)
+    pub async fn set_brake_time_offset_ms_async(
+        &mut self,
+        ms: i16,
+        playback_interval_us: u16,
+    ) -> Result<(), Error<E>> {
+        self.set_brake_time_offset_async(time_offset_units(ms, playback_interval_us))
+            .await
+    }
+
+    /// Check whether OTP memory has been programmed (async version)
+    pub async fn is_otp_programmed_async(&mut self) -> Result<bool, Error<E>> {
+        let control4 = self.device.control_4().read_async().await?;
+        Ok(control4.otp_status())
+    }
+
     /// Start auto-calibration process (async version)
     pub async fn start_auto_calibration_async(&mut self) -> Result<(), Error<E>> {
         // Set mode to auto-calibration
@@ -309,6 +1148,49 @@ where
         self.go_async().await
     }
 
+    /// Run auto-calibration, wait for it to finish and verify the result (async version)
+    pub async fn run_auto_calibration_async(
+        &mut self,
+        delay: &mut impl DelayNs,
+    ) -> Result<CalibrationResult, Error<E>> {
+        self.start_auto_calibration_async().await?;
+        if let Err(e) = self
+            .wait_until_done_async(delay, AUTO_CALIBRATION_TIMEOUT_US)
+            .await
+        {
+            if matches!(e, Error::Timeout) {
+                self.stop_async().await?;
+            }
+            return Err(e);
+        }
+
+        let status = self.get_status_async().await?;
+        if status.diagnostic_result || status.fault().is_some() {
+            return Err(Error::CalibrationFailed(status));
+        }
+
+        Ok(CalibrationResult {
+            compensation: self
+                .device
+                .auto_calib_comp_result()
+                .read_async()
+                .await?
+                .a_cal_comp(),
+            back_emf: self
+                .device
+                .auto_calib_back_emf_result()
+                .read_async()
+                .await?
+                .a_cal_bemf(),
+            bemf_gain: self
+                .device
+                .feedback_control()
+                .read_async()
+                .await?
+                .bemf_gain(),
+        })
+    }
+
     /// Start diagnostics process (async version)
     pub async fn start_diagnostics_async(&mut self) -> Result<(), Error<E>> {
         // Set mode to diagnostics
@@ -316,6 +1198,160 @@ where
         // Trigger diagnostics
         self.go_async().await
     }
+
+    /// Check whether diagnostics or auto-calibration is still in progress (async version)
+    pub async fn is_special_mode_running_async(&mut self) -> Result<bool, Error<E>> {
+        let mode = self.get_mode_async().await?;
+        if !matches!(
+            mode,
+            OperatingMode::Diagnostics | OperatingMode::AutoCalibration
+        ) {
+            return Ok(false);
+        }
+        self.is_active_async().await
+    }
+
+    /// Run a factory self-test of the device and actuator (async version)
+    pub async fn self_test_async(
+        &mut self,
+        delay: &mut impl DelayNs,
+        run_calibration: bool,
+    ) -> Result<SelfTestReport, Error<E>> {
+        let mut report = SelfTestReport {
+            device_id_ok: self.get_status_async().await?.device_id == EXPECTED_DEVICE_ID,
+            diagnostics_passed: false,
+            calibration_passed: None,
+            effect_played: false,
+        };
+        if !report.device_id_ok {
+            return Ok(report);
+        }
+
+        self.set_standby_async(false).await?;
+
+        self.start_diagnostics_async().await?;
+        report.diagnostics_passed = self.finish_self_test_step_async(delay).await?;
+
+        if run_calibration {
+            self.start_auto_calibration_async().await?;
+            report.calibration_passed = Some(self.finish_self_test_step_async(delay).await?);
+        }
+
+        self.set_mode_async(OperatingMode::Internal).await?;
+        self.set_single_effect_async(1).await?;
+        self.go_async().await?;
+        report.effect_played = self.finish_self_test_step_async(delay).await?;
+
+        Ok(report)
+    }
+
+    /// Wait for a self-test step and check the status register (async version)
+    async fn finish_self_test_step_async(
+        &mut self,
+        delay: &mut impl DelayNs,
+    ) -> Result<bool, Error<E>> {
+        match self
+            .wait_until_done_async(delay, SELF_TEST_STEP_TIMEOUT_US)
+            .await
+        {
+            Ok(()) => {}
+            Err(Error::Timeout) => {
+                self.stop_async().await?;
+                return Ok(false);
+            }
+            Err(e) => return Err(e),
+        }
+
+        let status = self.get_status_async().await?;
+        Ok(!status.diagnostic_result && status.fault().is_none())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I2C, E> Drv260xBuilder<I2C>
+where
+    I2C: AsyncI2c<Error = E>,
+{
+    /// Build the driver, verify the device ID and apply the accumulated configuration (async version)
+    pub async fn build_and_init_async(self) -> Result<Drv260x<I2C>, Error<E>> {
+        let (mut driver, config) = self.build();
+        driver.init_async().await?;
+        driver.configure_async(&config).await?;
+        Ok(driver)
+    }
+}
+
+/// Async one-time programmable (OTP) memory support for DRV2604L and DRV2605L.
+#[cfg(all(
+    feature = "async",
+    feature = "otp",
+    any(feature = "drv2605l", feature = "drv2604l")
+))]
+impl<I2C, E> Drv260x<I2C>
+where
+    I2C: AsyncI2c<Error = E>,
+{
+    /// Burn the current calibration/configuration registers into OTP memory (async version)
+    ///
+    /// # Warning
+    ///
+    /// **OTP memory can only be programmed ONCE.** See [`Drv260x::program_otp`].
+    pub async fn program_otp_async(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<E>> {
+        let control4 = self.device.control_4().read_async().await?;
+        if control4.otp_status() {
+            return Err(Error::InvalidConfig("OTP memory already programmed"));
+        }
+
+        self.device
+            .control_4()
+            .write_async(|reg| {
+                *reg = control4;
+                reg.set_otp_program(true);
+            })
+            .await?;
+
+        delay.delay_ms(OTP_PROGRAM_TIME_MS).await;
+
+        let control4 = self.device.control_4().read_async().await?;
+        if !control4.otp_status() {
+            return Err(Error::NotReady);
+        }
+
+        Ok(())
+    }
+}
+
+/// Async waveform RAM access for DRV2604 and DRV2604L.
+#[cfg(all(feature = "async", any(feature = "drv2604", feature = "drv2604l")))]
+impl<I2C, E> Drv260x<I2C>
+where
+    I2C: AsyncI2c<Error = E>,
+{
+    /// Write a waveform library image to the waveform RAM, starting at address 0 (async version)
+    ///
+    /// See [`Drv260x::write_ram_waveforms`].
+    pub async fn write_ram_waveforms_async(&mut self, data: &[u8]) -> Result<(), Error<E>> {
+        if data.len() > RAM_SIZE {
+            return Err(Error::InvalidConfig("RAM image exceeds waveform RAM size"));
+        }
+
+        self.device
+            .ram_address_upper_byte()
+            .write_async(|reg| reg.set_ram_addr_ub(0))
+            .await?;
+        self.device
+            .ram_address_lower_byte()
+            .write_async(|reg| reg.set_ram_addr_lb(0))
+            .await?;
+
+        for &byte in data {
+            self.device
+                .ram_data()
+                .write_async(|reg| reg.set_ram_data(byte))
+                .await?;
+        }
+        Ok(())
+    }
 }
 
 /// Async methods only available on DRV2605 and DRV2605L variants (ROM library and audio-to-vibe).
@@ -326,6 +1362,8 @@ where
 {
     /// Set library selection (async version)
     pub async fn set_library_async(&mut self, library: LibrarySelection) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         self.device
             .library_selection()
             .modify_async(|reg| reg.set_library_sel(library))
@@ -333,18 +1371,158 @@ where
         Ok(())
     }
 
+    /// Get the current library selection (async version)
+    pub async fn get_library_async(&mut self) -> Result<LibrarySelection, Error<E>> {
+        self.ensure_rom_library()?;
+
+        let reg = self.device.library_selection().read_async().await?;
+        Ok(reg.library_sel())
+    }
+
+    /// Select the ROM library suited to an actuator and rated voltage (async version)
+    pub async fn set_library_for_actuator_async(
+        &mut self,
+        actuator: Actuator,
+        rated_voltage_volts: f32,
+    ) -> Result<LibrarySelection, Error<E>> {
+        let library = config::library_for_actuator(actuator, rated_voltage_volts);
+        self.set_library_async(library).await?;
+        Ok(library)
+    }
+
     /// Set a single predefined effect in the first sequencer slot (async version)
     pub async fn set_single_effect_enum_async(&mut self, effect: Effect) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         let sequence = [WaveformEntry::from(effect), WaveformEntry::stop()];
         self.set_waveform_sequence_async(&sequence).await
     }
 
+    /// Load a single predefined effect, trigger playback and return its nominal duration (async version)
+    pub async fn play_effect_async(&mut self, effect: Effect) -> Result<u16, Error<E>> {
+        self.ensure_rom_library()?;
+
+        self.set_single_effect_enum_async(effect).await?;
+        self.go_async().await?;
+        Ok(effect.duration_ms())
+    }
+
+    /// Apply the effect's recommended brake factor, then play it (async version)
+    pub async fn play_effect_tuned_async(&mut self, effect: Effect) -> Result<u16, Error<E>> {
+        self.ensure_rom_library()?;
+
+        if let Some(brake_factor) = effect.recommended_brake_factor() {
+            self.device
+                .feedback_control()
+                .modify_async(|reg| reg.set_fb_brake_factor(brake_factor))
+                .await?;
+        }
+        self.play_effect_async(effect).await
+    }
+
+    /// Play a predefined effect once and restore the previous operating mode (async version)
+    pub async fn play_effect_once_async(
+        &mut self,
+        effect: Effect,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
+        let previous_mode = match self.current_mode {
+            Some(mode) => mode,
+            None => self.get_mode_async().await?,
+        };
+        self.set_mode_async(OperatingMode::Internal).await?;
+
+        let played = match self.play_effect_async(effect).await {
+            Ok(duration_ms) => {
+                self.wait_until_done_async(delay, playback_timeout_us(duration_ms))
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+        let stopped = if matches!(played, Err(Error::Timeout)) {
+            self.stop_async().await
+        } else {
+            Ok(())
+        };
+
+        // Restore the mode even if stopping failed, then report the first error
+        let restored = self.set_mode_async(previous_mode).await;
+        played.and(stopped).and(restored)
+    }
+
+    /// Play a strong click and wait for it to finish (async version)
+    pub async fn quick_click_async(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
+        self.ensure_mode_async(OperatingMode::Internal).await?;
+
+        let duration_ms = self.play_effect_async(Effect::StrongClick100).await?;
+        let played = self
+            .wait_until_done_async(delay, playback_timeout_us(duration_ms))
+            .await;
+        if matches!(played, Err(Error::Timeout)) {
+            self.stop_async().await?;
+        }
+        played
+    }
+
+    /// Play a predefined effect, wait for it to finish and enter standby (async version)
+    pub async fn play_and_standby_async(
+        &mut self,
+        effect: Effect,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
+        self.device
+            .mode()
+            .modify_async(|reg| {
+                reg.set_standby(false);
+                reg.set_mode(OperatingMode::Internal);
+            })
+            .await?;
+        self.current_mode = Some(OperatingMode::Internal);
+
+        let played = match self.play_effect_async(effect).await {
+            Ok(duration_ms) => {
+                self.wait_until_done_async(delay, playback_timeout_us(duration_ms))
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+        let stopped = if matches!(played, Err(Error::Timeout)) {
+            self.stop_async().await
+        } else {
+            Ok(())
+        };
+
+        self.enter_standby_async().await?;
+        stopped?;
+        played
+    }
+
+    /// Load predefined effects separated by a wait of `gap_ms` milliseconds (async version)
+    pub async fn set_effects_with_gaps_async(
+        &mut self,
+        effects: &[Effect],
+        gap_ms: u16,
+    ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
+        let slots = effects::interleave_gaps(effects, gap_ms)?;
+        self.write_sequencer_slots_async(&slots).await
+    }
+
     /// Configure audio-to-vibe control settings (async version)
     pub async fn set_audio_to_vibe_control_async(
         &mut self,
         filter: AthFilter,
         peak_time: AthPeakTime,
     ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         self.device
             .audio_to_vibe_control()
             .modify_async(|reg| {
@@ -355,11 +1533,23 @@ where
         Ok(())
     }
 
+    /// Get the audio-to-vibe filter and peak time settings (async version)
+    pub async fn get_audio_to_vibe_control_async(
+        &mut self,
+    ) -> Result<(AthFilter, AthPeakTime), Error<E>> {
+        self.ensure_rom_library()?;
+
+        let reg = self.device.audio_to_vibe_control().read_async().await?;
+        Ok((reg.ath_filter(), reg.ath_peak_time()))
+    }
+
     /// Set audio-to-vibe minimum input level (async version)
     pub async fn set_audio_to_vibe_min_input_level_async(
         &mut self,
         level: u8,
     ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         self.device
             .audio_to_vibe_min_input_level()
             .write_async(|reg| reg.set_ath_min_input(level))
@@ -372,6 +1562,8 @@ where
         &mut self,
         level: u8,
     ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         self.device
             .audio_to_vibe_max_input_level()
             .write_async(|reg| reg.set_ath_max_input(level))
@@ -384,6 +1576,8 @@ where
         &mut self,
         level: u8,
     ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         self.device
             .audio_to_vibe_min_output_drive()
             .write_async(|reg| reg.set_ath_min_drive(level))
@@ -396,10 +1590,347 @@ where
         &mut self,
         level: u8,
     ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         self.device
             .audio_to_vibe_max_output_drive()
             .write_async(|reg| reg.set_ath_max_drive(level))
             .await?;
         Ok(())
     }
+
+    /// Configure audio-to-vibe in one call and enter audio-to-vibe mode (async version)
+    pub async fn configure_audio_to_vibe_async(
+        &mut self,
+        cfg: &AudioToVibeConfig,
+    ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
+        self.device
+            .audio_to_vibe_control()
+            .write_async(|reg| {
+                reg.set_ath_filter(cfg.filter);
+                reg.set_ath_peak_time(cfg.peak_time);
+            })
+            .await?;
+        self.device
+            .audio_to_vibe_min_input_level()
+            .write_async(|reg| reg.set_ath_min_input(cfg.min_input_level))
+            .await?;
+        self.device
+            .audio_to_vibe_max_input_level()
+            .write_async(|reg| reg.set_ath_max_input(cfg.max_input_level))
+            .await?;
+        self.device
+            .audio_to_vibe_min_output_drive()
+            .write_async(|reg| reg.set_ath_min_drive(cfg.min_output_drive))
+            .await?;
+        self.device
+            .audio_to_vibe_max_output_drive()
+            .write_async(|reg| reg.set_ath_max_drive(cfg.max_output_drive))
+            .await?;
+
+        self.device
+            .control_1()
+            .modify_async(|reg| reg.set_ac_couple(true))
+            .await?;
+        self.device
+            .control_3()
+            .modify_async(|reg| reg.set_n_pwm_analog(true))
+            .await?;
+
+        self.set_mode_async(OperatingMode::AudioToVibe).await
+    }
+
+    /// Read back the audio-to-vibe configuration (async version)
+    pub async fn get_audio_to_vibe_config_async(&mut self) -> Result<AudioToVibeConfig, Error<E>> {
+        self.ensure_rom_library()?;
+
+        let control = self.device.audio_to_vibe_control().read_async().await?;
+        let min_input = self
+            .device
+            .audio_to_vibe_min_input_level()
+            .read_async()
+            .await?;
+        let max_input = self
+            .device
+            .audio_to_vibe_max_input_level()
+            .read_async()
+            .await?;
+        let min_drive = self
+            .device
+            .audio_to_vibe_min_output_drive()
+            .read_async()
+            .await?;
+        let max_drive = self
+            .device
+            .audio_to_vibe_max_output_drive()
+            .read_async()
+            .await?;
+
+        Ok(AudioToVibeConfig {
+            filter: control.ath_filter(),
+            peak_time: control.ath_peak_time(),
+            min_input_level: min_input.ath_min_input(),
+            max_input_level: max_input.ath_max_input(),
+            min_output_drive: min_drive.ath_min_drive(),
+            max_output_drive: max_drive.ath_max_drive(),
+        })
+    }
+}
+
+/// Playback in progress, returned by [`Drv260x::play_guarded_async`]
+///
+/// Dropping the guard before playback has finished polls a stop (GO clear) once. If that
+/// write does not complete immediately, or fails, the driver is left stop-pending and the
+/// stop is issued by the next [`Drv260x::clear_pending_stop_async`].
+#[cfg(feature = "async")]
+pub struct PlaybackGuard<'a, I2C: AsyncI2c> {
+    driver: &'a mut Drv260x<I2C>,
+    finished: bool,
+}
+
+#[cfg(feature = "async")]
+impl<I2C, E> PlaybackGuard<'_, I2C>
+where
+    I2C: AsyncI2c<Error = E>,
+{
+    /// Wait until playback completes (GO bit clears)
+    ///
+    /// Polls like `wait_until_done_async`. On timeout the guard is dropped and the driver
+    /// stays stop-pending.
+    pub async fn wait_async(
+        mut self,
+        delay: &mut impl DelayNs,
+        timeout_us: u32,
+    ) -> Result<(), Error<E>> {
+        self.driver.wait_until_done_async(delay, timeout_us).await?;
+        self.driver.stop_pending = false;
+        self.finished = true;
+        Ok(())
+    }
+
+    /// Stop playback now (clear GO bit)
+    pub async fn stop_async(mut self) -> Result<(), Error<E>> {
+        self.driver.stop_async().await?;
+        self.finished = true;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I2C: AsyncI2c> Drop for PlaybackGuard<'_, I2C> {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        // Drop cannot await: poll the stop once and defer it if the bus is not ready
+        let stopped = {
+            let stop = pin!(self.driver.stop_async());
+            let polled = stop.poll(&mut Context::from_waker(Waker::noop()));
+            matches!(polled, Poll::Ready(Ok(())))
+        };
+        if !stopped {
+            self.driver.stop_pending = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::I2C_ADDRESS;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+    /// Delay that never completes, to cancel a future mid-stream
+    struct NeverDelay;
+
+    impl DelayNs for NeverDelay {
+        async fn delay_ns(&mut self, _ns: u32) {
+            core::future::pending::<()>().await
+        }
+    }
+
+    /// Poll a future once and drop it
+    fn poll_once<F: Future>(future: F) -> Poll<F::Output> {
+        let mut future = pin!(future);
+        future
+            .as_mut()
+            .poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    /// Run a future that never waits on anything but the mock bus
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    /// Expect a read of `value` from `register`
+    fn read(register: u8, value: u8) -> I2cTransaction {
+        I2cTransaction::write_read(I2C_ADDRESS, vec![register], vec![value])
+    }
+
+    /// Expect a write of `data` starting at `register`
+    fn write(register: u8, data: &[u8]) -> I2cTransaction {
+        let mut bytes = vec![register];
+        bytes.extend_from_slice(data);
+        I2cTransaction::write(I2C_ADDRESS, bytes)
+    }
+
+    #[test]
+    fn cancelled_stream_rtp_is_silenced_on_recovery() {
+        check_cancelled_stream_rtp_recovery(0xA0, 0x00);
+    }
+
+    #[test]
+    fn cancelled_unsigned_stream_rtp_recovers_to_mid_scale() {
+        check_cancelled_stream_rtp_recovery(0xA8, 0x80);
+    }
+
+    fn check_cancelled_stream_rtp_recovery(control3: u8, idle: u8) {
+        let expectations = [
+            read(0x01, 0x00),
+            write(0x01, &[0x05]),
+            write(0x02, &[0x40]),
+            // Recovery writes the no-drive value for DATA_FORMAT_RTP; GO is not touched
+            read(0x1D, control3),
+            write(0x02, &[idle]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut delay = NeverDelay;
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        let stream = haptic.stream_rtp_async([0x40, 0x7F], &mut delay, 200);
+        assert!(poll_once(stream).is_pending());
+        assert!(haptic.is_stop_pending());
+
+        block_on(haptic.clear_pending_stop_async()).unwrap();
+        assert!(!haptic.is_stop_pending());
+
+        i2c.done();
+    }
+
+    #[test]
+    fn set_timing_offsets_async_matches_sync() {
+        let expectations = [write(0x0D, &[0x05, 0xFF, 0x0A, 0x80])];
+        let mut sync_i2c = I2cMock::new(&expectations);
+        let mut async_i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(sync_i2c.clone());
+        haptic.set_timing_offsets(5, -1, 10, -128).unwrap();
+        let mut haptic = Drv260x::new(async_i2c.clone());
+        block_on(haptic.set_timing_offsets_async(5, -1, 10, -128)).unwrap();
+
+        sync_i2c.done();
+        async_i2c.done();
+    }
+
+    #[test]
+    fn get_status_async_decodes_like_sync() {
+        for raw in [0x00, 0x1F, 0x69, 0xE2, 0xFF] {
+            let mut sync_i2c = I2cMock::new(&[read(0x00, raw)]);
+            let mut async_i2c = I2cMock::new(&[read(0x00, raw)]);
+
+            let sync_status = Drv260x::new(sync_i2c.clone()).get_status().unwrap();
+            let mut haptic = Drv260x::new(async_i2c.clone());
+            let async_status = block_on(haptic.get_status_async()).unwrap();
+
+            assert_eq!(sync_status, async_status);
+            assert_eq!(async_status.raw, raw);
+
+            sync_i2c.done();
+            async_i2c.done();
+        }
+    }
+
+    #[test]
+    fn get_status_async_decodes_each_field() {
+        // DRV2605 ID with overcurrent and DIAG_RESULT set
+        let mut i2c = I2cMock::new(&[read(0x00, 0x69)]);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        let status = block_on(haptic.get_status_async()).unwrap();
+
+        assert!(status.overcurrent_detected);
+        assert!(!status.overtemperature_detected);
+        assert!(!status.feedback_status);
+        assert!(status.diagnostic_result);
+        assert!(!status.illegal_address);
+        assert_eq!(status.device_id, 3);
+
+        i2c.done();
+    }
+
+    #[test]
+    fn arm_external_trigger_async_loads_entry_then_sets_mode() {
+        let expectations = [
+            write(0x04, &[0x2F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            read(0x01, 0x00),
+            write(0x01, &[0x01]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        block_on(haptic.arm_external_trigger_async(WaveformEntry::effect(47), false)).unwrap();
+
+        i2c.done();
+    }
+
+    #[test]
+    fn dropped_guard_stops_playback() {
+        let expectations = [write(0x0C, &[0x01]), write(0x0C, &[0x00])];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        let guard = block_on(haptic.play_guarded_async()).unwrap();
+        drop(guard);
+        assert!(!haptic.is_stop_pending());
+
+        i2c.done();
+    }
+
+    #[test]
+    fn dropped_guard_defers_failed_stop_to_next_playback() {
+        let expectations = [
+            write(0x0C, &[0x01]),
+            write(0x0C, &[0x00]).with_error(embedded_hal::i2c::ErrorKind::Other),
+            // Next guarded playback flushes the pending stop first
+            write(0x0C, &[0x00]),
+            write(0x0C, &[0x01]),
+            write(0x0C, &[0x00]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        let guard = block_on(haptic.play_guarded_async()).unwrap();
+        drop(guard);
+        assert!(haptic.is_stop_pending());
+
+        let guard = block_on(haptic.play_guarded_async()).unwrap();
+        drop(guard);
+
+        i2c.done();
+    }
+
+    #[test]
+    fn finished_guard_clears_stop_pending() {
+        let expectations = [write(0x0C, &[0x01]), read(0x0C, 0x00)];
+        let mut i2c = I2cMock::new(&expectations);
+        let mut delay = NoopDelay::new();
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        let guard = block_on(haptic.play_guarded_async()).unwrap();
+        block_on(guard.wait_async(&mut delay, 1_000)).unwrap();
+        assert!(!haptic.is_stop_pending());
+
+        i2c.done();
+    }
 }
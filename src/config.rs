@@ -0,0 +1,301 @@
+//! Construction-time configuration and builder
+//!
+//! This module contains the [`Config`] struct describing the actuator setup applied after
+//! initialization, and the [`Drv260xBuilder`] used to assemble a driver fluently:
+//!
+//! ```rust,ignore
+//! use drv260x::{Actuator, Drv260x};
+//!
+//! let mut haptic = Drv260x::builder(i2c)
+//!     .actuator(Actuator::Lra)
+//!     .rated_voltage_volts(2.0)
+//!     .build_and_init()?;
+//! ```
+
+use crate::ll::OperatingMode;
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+use crate::ll::{AthFilter, AthPeakTime, LibrarySelection};
+use crate::{Actuator, Drv260x, Sequence, WaveformEntry, I2C_ADDRESS, SEQUENCER_SLOTS};
+
+/// Rated voltage resolution for ERM actuators (volts per LSB, average voltage)
+const ERM_RATED_VOLTAGE_STEP: f32 = 21.18e-3;
+
+/// Rated voltage resolution for LRA actuators (volts per LSB, RMS voltage)
+const LRA_RATED_VOLTAGE_STEP: f32 = 20.58e-3;
+
+/// Overdrive clamp voltage resolution (volts per LSB, peak voltage)
+const OD_CLAMP_STEP: f32 = 21.96e-3;
+
+/// Actuator configuration applied after device initialization
+///
+/// Fields left as `None` keep the device's current register value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    /// Actuator type (ERM or LRA)
+    pub actuator: Actuator,
+    /// Raw RATED_VOLTAGE register value
+    pub rated_voltage: Option<u8>,
+    /// Raw OD_CLAMP register value
+    pub overdrive_clamp_voltage: Option<u8>,
+    /// Operating mode to enter after initialization
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_remote::OperatingModeDef")
+    )]
+    pub mode: OperatingMode,
+    /// Waveform sequence to load into the sequencer (all 8 slots)
+    pub sequence: Option<[WaveformEntry; SEQUENCER_SLOTS]>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            actuator: Actuator::Erm,
+            rated_voltage: None,
+            overdrive_clamp_voltage: None,
+            mode: OperatingMode::Internal,
+            sequence: None,
+        }
+    }
+}
+
+/// Audio-to-vibe configuration (DRV2605/DRV2605L only)
+///
+/// Holds the audio-to-haptic filter and peak time, and the input level and output drive
+/// ranges. The default matches the device reset values.
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AudioToVibeConfig {
+    /// Audio-to-haptic low-pass filter
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_remote::AthFilterDef"))]
+    pub filter: AthFilter,
+    /// Audio-to-haptic peak detection time
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_remote::AthPeakTimeDef"))]
+    pub peak_time: AthPeakTime,
+    /// Minimum input level (ATH_MIN_INPUT)
+    pub min_input_level: u8,
+    /// Maximum input level (ATH_MAX_INPUT)
+    pub max_input_level: u8,
+    /// Minimum output drive (ATH_MIN_DRIVE)
+    pub min_output_drive: u8,
+    /// Maximum output drive (ATH_MAX_DRIVE)
+    pub max_output_drive: u8,
+}
+
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+impl Default for AudioToVibeConfig {
+    fn default() -> Self {
+        Self {
+            filter: AthFilter::Hz125,
+            peak_time: AthPeakTime::Ms20,
+            min_input_level: 0x19,
+            max_input_level: 0xFF,
+            min_output_drive: 0x19,
+            max_output_drive: 0xFF,
+        }
+    }
+}
+
+/// Convert a rated voltage in volts to the RATED_VOLTAGE register value
+///
+/// ERM actuators are specified by average voltage (21.18 mV/LSB), LRA actuators by RMS
+/// voltage (20.58 mV/LSB). The LRA conversion ignores the small sample-time/resonance
+/// correction term from the datasheet, so treat the result as a starting point for
+/// auto-calibration. Values out of range saturate to 0 or 255.
+pub fn rated_voltage_from_volts(actuator: Actuator, volts: f32) -> u8 {
+    let step = match actuator {
+        Actuator::Erm => ERM_RATED_VOLTAGE_STEP,
+        Actuator::Lra => LRA_RATED_VOLTAGE_STEP,
+    };
+    // `as u8` saturates, and adding 0.5 rounds to nearest for positive values
+    (volts / step + 0.5) as u8
+}
+
+/// Convert a RATED_VOLTAGE register value to volts
+///
+/// Inverse of [`rated_voltage_from_volts`], with the same simplifications.
+pub fn rated_voltage_to_volts(actuator: Actuator, value: u8) -> f32 {
+    let step = match actuator {
+        Actuator::Erm => ERM_RATED_VOLTAGE_STEP,
+        Actuator::Lra => LRA_RATED_VOLTAGE_STEP,
+    };
+    f32::from(value) * step
+}
+
+/// Convert an OD_CLAMP register value to volts
+///
+/// Uses the 21.96 mV/LSB peak-voltage resolution; for ERM actuators in open loop the
+/// datasheet applies an additional drive/blanking time correction that is ignored here.
+pub fn overdrive_clamp_to_volts(value: u8) -> f32 {
+    f32::from(value) * OD_CLAMP_STEP
+}
+
+/// Pick a ROM library for an actuator and rated voltage (DRV2605/DRV2605L only)
+///
+/// Follows the datasheet library table:
+///
+/// | Library | Actuator | Rated voltage | Rise time  |
+/// |---------|----------|---------------|------------|
+/// | A       | ERM      | 1.3 V         | 40-60 ms   |
+/// | B       | ERM      | 3 V           | 40-60 ms   |
+/// | C       | ERM      | 3 V           | 60-80 ms   |
+/// | D       | ERM      | 3 V           | 100-140 ms |
+/// | E       | ERM      | 3 V           | > 140 ms   |
+/// | F       | ERM      | 4.5 V         | 35-45 ms   |
+/// | LRA     | LRA      | any           | -          |
+///
+/// ERM actuators below 2 V map to library A, 4 V and above to library F, and everything
+/// in between to library B. Libraries C-E only differ in rise time, which cannot be
+/// derived from the voltage; select them with `set_library()` for slow actuators.
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+pub fn library_for_actuator(actuator: Actuator, rated_voltage_volts: f32) -> LibrarySelection {
+    match actuator {
+        Actuator::Lra => LibrarySelection::LRA,
+        Actuator::Erm if rated_voltage_volts < 2.0 => LibrarySelection::A,
+        Actuator::Erm if rated_voltage_volts >= 4.0 => LibrarySelection::F,
+        Actuator::Erm => LibrarySelection::B,
+    }
+}
+
+/// Builder for a [`Drv260x`] driver with construction-time configuration
+///
+/// Created with [`Drv260x::builder`]. Call `build()` to get an uninitialized driver and
+/// the resolved [`Config`], or `build_and_init()` to verify the device ID and apply the
+/// configuration in one step.
+pub struct Drv260xBuilder<I2C> {
+    i2c: I2C,
+    address: u8,
+    config: Config,
+    rated_voltage_volts: Option<f32>,
+}
+
+impl<I2C> Drv260xBuilder<I2C> {
+    pub(crate) fn new(i2c: I2C) -> Self {
+        Self {
+            i2c,
+            address: I2C_ADDRESS,
+            config: Config::default(),
+            rated_voltage_volts: None,
+        }
+    }
+
+    /// Set the 7-bit I2C address (defaults to [`I2C_ADDRESS`])
+    pub fn address(mut self, address: u8) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// Set the actuator type
+    pub fn actuator(mut self, actuator: Actuator) -> Self {
+        self.config.actuator = actuator;
+        self
+    }
+
+    /// Set the raw RATED_VOLTAGE register value
+    pub fn rated_voltage(mut self, value: u8) -> Self {
+        self.config.rated_voltage = Some(value);
+        self.rated_voltage_volts = None;
+        self
+    }
+
+    /// Set the rated voltage in volts
+    ///
+    /// The value is converted for the selected actuator when the driver is built, see
+    /// [`rated_voltage_from_volts`].
+    pub fn rated_voltage_volts(mut self, volts: f32) -> Self {
+        self.rated_voltage_volts = Some(volts);
+        self
+    }
+
+    /// Set the raw OD_CLAMP register value
+    pub fn overdrive_clamp_voltage(mut self, value: u8) -> Self {
+        self.config.overdrive_clamp_voltage = Some(value);
+        self
+    }
+
+    /// Set the operating mode entered after initialization (defaults to internal trigger)
+    pub fn mode(mut self, mode: OperatingMode) -> Self {
+        self.config.mode = mode;
+        self
+    }
+
+    /// Set the waveform sequence loaded into the sequencer after initialization
+    pub fn sequence<const N: usize>(mut self, sequence: &Sequence<N>) -> Self {
+        self.config.sequence = Some(sequence.to_slots());
+        self
+    }
+
+    /// Build the driver without touching the bus
+    ///
+    /// Returns the driver together with the resolved [`Config`], which can be applied later
+    /// with `configure()`.
+    pub fn build(self) -> (Drv260x<I2C>, Config) {
+        let mut config = self.config;
+        if let Some(volts) = self.rated_voltage_volts {
+            config.rated_voltage = Some(rated_voltage_from_volts(config.actuator, volts));
+        }
+
+        (Drv260x::with_address(self.i2c, self.address), config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rated_voltage_round_trips_every_register_value() {
+        for actuator in [Actuator::Erm, Actuator::Lra] {
+            for value in 0..=u8::MAX {
+                let volts = rated_voltage_to_volts(actuator, value);
+                assert_eq!(
+                    rated_voltage_from_volts(actuator, volts),
+                    value,
+                    "{actuator:?} {value:#04x}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rated_voltage_from_volts_rounds_and_saturates() {
+        // 3.0 V / 21.18 mV = 141.6, 2.0 V / 20.58 mV = 97.2
+        assert_eq!(rated_voltage_from_volts(Actuator::Erm, 3.0), 142);
+        assert_eq!(rated_voltage_from_volts(Actuator::Lra, 2.0), 97);
+        assert_eq!(rated_voltage_from_volts(Actuator::Erm, -1.0), 0);
+        assert_eq!(rated_voltage_from_volts(Actuator::Lra, 10.0), u8::MAX);
+    }
+
+    #[test]
+    fn overdrive_clamp_to_volts_uses_peak_resolution() {
+        assert_eq!(overdrive_clamp_to_volts(0), 0.0);
+        assert!((overdrive_clamp_to_volts(u8::MAX) - 5.5998).abs() < 1e-3);
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn library_for_actuator_switches_at_2_and_4_volts() {
+        let erm = |volts| library_for_actuator(Actuator::Erm, volts);
+        assert_eq!(erm(1.3), LibrarySelection::A);
+        assert_eq!(erm(1.99), LibrarySelection::A);
+        assert_eq!(erm(2.0), LibrarySelection::B);
+        assert_eq!(erm(3.99), LibrarySelection::B);
+        assert_eq!(erm(4.0), LibrarySelection::F);
+        assert_eq!(erm(4.5), LibrarySelection::F);
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn library_for_actuator_ignores_voltage_for_lra() {
+        for volts in [0.0, 2.0, 4.0] {
+            assert_eq!(
+                library_for_actuator(Actuator::Lra, volts),
+                LibrarySelection::LRA
+            );
+        }
+    }
+}
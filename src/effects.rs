@@ -4,6 +4,10 @@
 //! haptic effects from the DRV260X ROM library, as well as utilities for
 //! working with waveform sequences.
 
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+use crate::ll::FbBrakeFactor;
+use crate::{Error, SEQUENCER_SLOTS};
+
 /// Predefined haptic effects from the DRV260X ROM library
 ///
 /// These effects are pre-programmed waveforms stored in the device's ROM.
@@ -14,6 +18,7 @@
 #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Effect {
     /// Strong Click - 100%
@@ -264,9 +269,593 @@ pub enum Effect {
     SmoothHum5_10 = 123,
 }
 
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+impl Effect {
+    /// All ROM library effects in effect ID order
+    pub const ALL: [Effect; 123] = [
+        Effect::StrongClick100,
+        Effect::StrongClick60,
+        Effect::StrongClick30,
+        Effect::SharpClick100,
+        Effect::SharpClick60,
+        Effect::SharpClick30,
+        Effect::SoftBump100,
+        Effect::SoftBump60,
+        Effect::SoftBump30,
+        Effect::DoubleClick100,
+        Effect::DoubleClick60,
+        Effect::TripleClick100,
+        Effect::SoftFuzz60,
+        Effect::StrongBuzz100,
+        Effect::Alert750ms,
+        Effect::Alert1000ms,
+        Effect::StrongClick1_100,
+        Effect::StrongClick2_80,
+        Effect::StrongClick3_60,
+        Effect::StrongClick4_30,
+        Effect::MediumClick1_100,
+        Effect::MediumClick2_80,
+        Effect::MediumClick3_60,
+        Effect::SharpTick1_100,
+        Effect::SharpTick2_80,
+        Effect::SharpTick3_60,
+        Effect::ShortDoubleClickStrong1_100,
+        Effect::ShortDoubleClickStrong2_80,
+        Effect::ShortDoubleClickStrong3_60,
+        Effect::ShortDoubleClickStrong4_30,
+        Effect::ShortDoubleClickMedium1_100,
+        Effect::ShortDoubleClickMedium2_80,
+        Effect::ShortDoubleClickMedium3_60,
+        Effect::ShortDoubleSharpTick1_100,
+        Effect::ShortDoubleSharpTick2_80,
+        Effect::ShortDoubleSharpTick3_60,
+        Effect::LongDoubleSharpClickStrong1_100,
+        Effect::LongDoubleSharpClickStrong2_80,
+        Effect::LongDoubleSharpClickStrong3_60,
+        Effect::LongDoubleSharpClickStrong4_30,
+        Effect::LongDoubleSharpClickMedium1_100,
+        Effect::LongDoubleSharpClickMedium2_80,
+        Effect::LongDoubleSharpClickMedium3_60,
+        Effect::LongDoubleSharpTick1_100,
+        Effect::LongDoubleSharpTick2_80,
+        Effect::LongDoubleSharpTick3_60,
+        Effect::Buzz1_100,
+        Effect::Buzz2_80,
+        Effect::Buzz3_60,
+        Effect::Buzz4_40,
+        Effect::Buzz5_20,
+        Effect::PulsingStrong1_100,
+        Effect::PulsingStrong2_60,
+        Effect::PulsingMedium1_100,
+        Effect::PulsingMedium2_60,
+        Effect::PulsingSharp1_100,
+        Effect::PulsingSharp2_60,
+        Effect::TransitionClick1_100,
+        Effect::TransitionClick2_80,
+        Effect::TransitionClick3_60,
+        Effect::TransitionClick4_40,
+        Effect::TransitionClick5_20,
+        Effect::TransitionClick6_10,
+        Effect::TransitionHum1_100,
+        Effect::TransitionHum2_80,
+        Effect::TransitionHum3_60,
+        Effect::TransitionHum4_40,
+        Effect::TransitionHum5_20,
+        Effect::TransitionHum6_10,
+        Effect::TransitionRampDownLongSmooth1_100to0,
+        Effect::TransitionRampDownLongSmooth2_100to0,
+        Effect::TransitionRampDownMediumSmooth1_100to0,
+        Effect::TransitionRampDownMediumSmooth2_100to0,
+        Effect::TransitionRampDownShortSmooth1_100to0,
+        Effect::TransitionRampDownShortSmooth2_100to0,
+        Effect::TransitionRampDownLongSharp1_100to0,
+        Effect::TransitionRampDownLongSharp2_100to0,
+        Effect::TransitionRampDownMediumSharp1_100to0,
+        Effect::TransitionRampDownMediumSharp2_100to0,
+        Effect::TransitionRampDownShortSharp1_100to0,
+        Effect::TransitionRampDownShortSharp2_100to0,
+        Effect::TransitionRampUpLongSmooth1_0to100,
+        Effect::TransitionRampUpLongSmooth2_0to100,
+        Effect::TransitionRampUpMediumSmooth1_0to100,
+        Effect::TransitionRampUpMediumSmooth2_0to100,
+        Effect::TransitionRampUpShortSmooth1_0to100,
+        Effect::TransitionRampUpShortSmooth2_0to100,
+        Effect::TransitionRampUpLongSharp1_0to100,
+        Effect::TransitionRampUpLongSharp2_0to100,
+        Effect::TransitionRampUpMediumSharp1_0to100,
+        Effect::TransitionRampUpMediumSharp2_0to100,
+        Effect::TransitionRampUpShortSharp1_0to100,
+        Effect::TransitionRampUpShortSharp2_0to100,
+        Effect::TransitionRampDownLongSmooth1_50to0,
+        Effect::TransitionRampDownLongSmooth2_50to0,
+        Effect::TransitionRampDownMediumSmooth1_50to0,
+        Effect::TransitionRampDownMediumSmooth2_50to0,
+        Effect::TransitionRampDownShortSmooth1_50to0,
+        Effect::TransitionRampDownShortSmooth2_50to0,
+        Effect::TransitionRampDownLongSharp1_50to0,
+        Effect::TransitionRampDownLongSharp2_50to0,
+        Effect::TransitionRampDownMediumSharp1_50to0,
+        Effect::TransitionRampDownMediumSharp2_50to0,
+        Effect::TransitionRampDownShortSharp1_50to0,
+        Effect::TransitionRampDownShortSharp2_50to0,
+        Effect::TransitionRampUpLongSmooth1_0to50,
+        Effect::TransitionRampUpLongSmooth2_0to50,
+        Effect::TransitionRampUpMediumSmooth1_0to50,
+        Effect::TransitionRampUpMediumSmooth2_0to50,
+        Effect::TransitionRampUpShortSmooth1_0to50,
+        Effect::TransitionRampUpShortSmooth2_0to50,
+        Effect::TransitionRampUpLongSharp1_0to50,
+        Effect::TransitionRampUpLongSharp2_0to50,
+        Effect::TransitionRampUpMediumSharp1_0to50,
+        Effect::TransitionRampUpMediumSharp2_0to50,
+        Effect::TransitionRampUpShortSharp1_0to50,
+        Effect::TransitionRampUpShortSharp2_0to50,
+        Effect::LongBuzzForProgrammaticStopping100,
+        Effect::SmoothHum1_50,
+        Effect::SmoothHum2_40,
+        Effect::SmoothHum3_30,
+        Effect::SmoothHum4_20,
+        Effect::SmoothHum5_10,
+    ];
+
+    /// Iterate over all ROM library effects in effect ID order
+    pub fn all() -> impl Iterator<Item = Effect> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Find effects of a category with at least the given peak intensity in percent
+    ///
+    /// ```rust,ignore
+    /// // All full-strength clicks
+    /// for effect in Effect::find(EffectCategory::Click, 100) {
+    ///     println!("{}", effect.name());
+    /// }
+    /// ```
+    pub fn find(category: EffectCategory, min_intensity: u8) -> impl Iterator<Item = Effect> {
+        Self::all()
+            .filter(move |effect| effect.category() == category)
+            .filter(move |effect| effect.intensity() >= min_intensity)
+    }
+
+    /// Get the category of the effect
+    pub fn category(&self) -> EffectCategory {
+        match *self as u8 {
+            13 | 14 | 47..=51 | 118 => EffectCategory::Buzz,
+            15 | 16 => EffectCategory::Alert,
+            52..=57 => EffectCategory::Pulsing,
+            58..=69 => EffectCategory::Transition,
+            // Ramps alternate between blocks of 12 ramp-down and 12 ramp-up effects
+            id @ 70..=117 if (id - 70) % 24 < 12 => EffectCategory::RampDown,
+            70..=117 => EffectCategory::RampUp,
+            119..=123 => EffectCategory::Hum,
+            _ => EffectCategory::Click,
+        }
+    }
+
+    /// Get a suggested feedback brake factor for the effect, if it has one
+    ///
+    /// A coarse per-category mapping: clicks want a strong brake for a crisp stop, ramp-ups
+    /// a light one, and hums and ramp-downs, which already fade out, no brake at all.
+    /// Buzzes, alerts, pulses and transitions return `None` and are best left at the
+    /// configured factor. The brake factor only applies in closed-loop operation.
+    pub fn recommended_brake_factor(&self) -> Option<FbBrakeFactor> {
+        match self.category() {
+            EffectCategory::Click => Some(FbBrakeFactor::X4),
+            EffectCategory::RampUp => Some(FbBrakeFactor::X2),
+            EffectCategory::RampDown | EffectCategory::Hum => Some(FbBrakeFactor::Disabled),
+            EffectCategory::Buzz
+            | EffectCategory::Alert
+            | EffectCategory::Pulsing
+            | EffectCategory::Transition => None,
+        }
+    }
+
+    /// Pick the variant of `base` whose intensity is closest to `target_percent`
+    ///
+    /// ROM effects cannot be scaled, but many come in several intensities (e.g. Strong
+    /// Click 100/60/30%). This chooses the closest of those by [`intensity`](Self::intensity),
+    /// preferring the stronger variant on a tie. Effects without intensity variants are
+    /// returned unchanged.
+    pub fn nearest_intensity(base: Effect, target_percent: u8) -> Effect {
+        let id = base as u8;
+        let (first, last) = match INTENSITY_FAMILIES
+            .iter()
+            .find(|&&(first, last)| (first..=last).contains(&id))
+        {
+            Some(&family) => family,
+            None => return base,
+        };
+
+        Self::ALL[usize::from(first - 1)..usize::from(last)]
+            .iter()
+            .copied()
+            .min_by_key(|effect| effect.intensity().abs_diff(target_percent))
+            .unwrap_or(base)
+    }
+
+    /// Get the peak intensity of the effect in percent, as given in its name
+    ///
+    /// For ramps this is the higher end of the ramp (e.g. 50 for a 0 to 50% ramp up).
+    pub fn intensity(&self) -> u8 {
+        EFFECT_INTENSITY[usize::from(*self as u8 - 1)]
+    }
+
+    /// Get the approximate nominal playback duration of the effect in milliseconds
+    ///
+    /// Only the alerts have a duration specified by the datasheet; the other values are
+    /// approximate figures per effect family, intended for scheduling the next action. Actual
+    /// durations vary with the selected library, actuator and timing offsets.
+    pub fn duration_ms(&self) -> u16 {
+        effect_duration_ms(*self as u8)
+    }
+
+    /// Get the short human-readable name of the effect (e.g. `"Strong Click 100%"`)
+    ///
+    /// Lighter than `Debug` formatting, for logging or menus.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Effect::StrongClick100 => "Strong Click 100%",
+            Effect::StrongClick60 => "Strong Click 60%",
+            Effect::StrongClick30 => "Strong Click 30%",
+            Effect::SharpClick100 => "Sharp Click 100%",
+            Effect::SharpClick60 => "Sharp Click 60%",
+            Effect::SharpClick30 => "Sharp Click 30%",
+            Effect::SoftBump100 => "Soft Bump 100%",
+            Effect::SoftBump60 => "Soft Bump 60%",
+            Effect::SoftBump30 => "Soft Bump 30%",
+            Effect::DoubleClick100 => "Double Click 100%",
+            Effect::DoubleClick60 => "Double Click 60%",
+            Effect::TripleClick100 => "Triple Click 100%",
+            Effect::SoftFuzz60 => "Soft Fuzz 60%",
+            Effect::StrongBuzz100 => "Strong Buzz 100%",
+            Effect::Alert750ms => "750 ms Alert 100%",
+            Effect::Alert1000ms => "1000 ms Alert 100%",
+            Effect::StrongClick1_100 => "Strong Click 1 100%",
+            Effect::StrongClick2_80 => "Strong Click 2 80%",
+            Effect::StrongClick3_60 => "Strong Click 3 60%",
+            Effect::StrongClick4_30 => "Strong Click 4 30%",
+            Effect::MediumClick1_100 => "Medium Click 1 100%",
+            Effect::MediumClick2_80 => "Medium Click 2 80%",
+            Effect::MediumClick3_60 => "Medium Click 3 60%",
+            Effect::SharpTick1_100 => "Sharp Tick 1 100%",
+            Effect::SharpTick2_80 => "Sharp Tick 2 80%",
+            Effect::SharpTick3_60 => "Sharp Tick 3 60%",
+            Effect::ShortDoubleClickStrong1_100 => "Short Double Click Strong 1 100%",
+            Effect::ShortDoubleClickStrong2_80 => "Short Double Click Strong 2 80%",
+            Effect::ShortDoubleClickStrong3_60 => "Short Double Click Strong 3 60%",
+            Effect::ShortDoubleClickStrong4_30 => "Short Double Click Strong 4 30%",
+            Effect::ShortDoubleClickMedium1_100 => "Short Double Click Medium 1 100%",
+            Effect::ShortDoubleClickMedium2_80 => "Short Double Click Medium 2 80%",
+            Effect::ShortDoubleClickMedium3_60 => "Short Double Click Medium 3 60%",
+            Effect::ShortDoubleSharpTick1_100 => "Short Double Sharp Tick 1 100%",
+            Effect::ShortDoubleSharpTick2_80 => "Short Double Sharp Tick 2 80%",
+            Effect::ShortDoubleSharpTick3_60 => "Short Double Sharp Tick 3 60%",
+            Effect::LongDoubleSharpClickStrong1_100 => "Long Double Sharp Click Strong 1 100%",
+            Effect::LongDoubleSharpClickStrong2_80 => "Long Double Sharp Click Strong 2 80%",
+            Effect::LongDoubleSharpClickStrong3_60 => "Long Double Sharp Click Strong 3 60%",
+            Effect::LongDoubleSharpClickStrong4_30 => "Long Double Sharp Click Strong 4 30%",
+            Effect::LongDoubleSharpClickMedium1_100 => "Long Double Sharp Click Medium 1 100%",
+            Effect::LongDoubleSharpClickMedium2_80 => "Long Double Sharp Click Medium 2 80%",
+            Effect::LongDoubleSharpClickMedium3_60 => "Long Double Sharp Click Medium 3 60%",
+            Effect::LongDoubleSharpTick1_100 => "Long Double Sharp Tick 1 100%",
+            Effect::LongDoubleSharpTick2_80 => "Long Double Sharp Tick 2 80%",
+            Effect::LongDoubleSharpTick3_60 => "Long Double Sharp Tick 3 60%",
+            Effect::Buzz1_100 => "Buzz 1 100%",
+            Effect::Buzz2_80 => "Buzz 2 80%",
+            Effect::Buzz3_60 => "Buzz 3 60%",
+            Effect::Buzz4_40 => "Buzz 4 40%",
+            Effect::Buzz5_20 => "Buzz 5 20%",
+            Effect::PulsingStrong1_100 => "Pulsing Strong 1 100%",
+            Effect::PulsingStrong2_60 => "Pulsing Strong 2 60%",
+            Effect::PulsingMedium1_100 => "Pulsing Medium 1 100%",
+            Effect::PulsingMedium2_60 => "Pulsing Medium 2 60%",
+            Effect::PulsingSharp1_100 => "Pulsing Sharp 1 100%",
+            Effect::PulsingSharp2_60 => "Pulsing Sharp 2 60%",
+            Effect::TransitionClick1_100 => "Transition Click 1 100%",
+            Effect::TransitionClick2_80 => "Transition Click 2 80%",
+            Effect::TransitionClick3_60 => "Transition Click 3 60%",
+            Effect::TransitionClick4_40 => "Transition Click 4 40%",
+            Effect::TransitionClick5_20 => "Transition Click 5 20%",
+            Effect::TransitionClick6_10 => "Transition Click 6 10%",
+            Effect::TransitionHum1_100 => "Transition Hum 1 100%",
+            Effect::TransitionHum2_80 => "Transition Hum 2 80%",
+            Effect::TransitionHum3_60 => "Transition Hum 3 60%",
+            Effect::TransitionHum4_40 => "Transition Hum 4 40%",
+            Effect::TransitionHum5_20 => "Transition Hum 5 20%",
+            Effect::TransitionHum6_10 => "Transition Hum 6 10%",
+            Effect::TransitionRampDownLongSmooth1_100to0 => {
+                "Transition Ramp Down Long Smooth 1 100 to 0%"
+            }
+            Effect::TransitionRampDownLongSmooth2_100to0 => {
+                "Transition Ramp Down Long Smooth 2 100 to 0%"
+            }
+            Effect::TransitionRampDownMediumSmooth1_100to0 => {
+                "Transition Ramp Down Medium Smooth 1 100 to 0%"
+            }
+            Effect::TransitionRampDownMediumSmooth2_100to0 => {
+                "Transition Ramp Down Medium Smooth 2 100 to 0%"
+            }
+            Effect::TransitionRampDownShortSmooth1_100to0 => {
+                "Transition Ramp Down Short Smooth 1 100 to 0%"
+            }
+            Effect::TransitionRampDownShortSmooth2_100to0 => {
+                "Transition Ramp Down Short Smooth 2 100 to 0%"
+            }
+            Effect::TransitionRampDownLongSharp1_100to0 => {
+                "Transition Ramp Down Long Sharp 1 100 to 0%"
+            }
+            Effect::TransitionRampDownLongSharp2_100to0 => {
+                "Transition Ramp Down Long Sharp 2 100 to 0%"
+            }
+            Effect::TransitionRampDownMediumSharp1_100to0 => {
+                "Transition Ramp Down Medium Sharp 1 100 to 0%"
+            }
+            Effect::TransitionRampDownMediumSharp2_100to0 => {
+                "Transition Ramp Down Medium Sharp 2 100 to 0%"
+            }
+            Effect::TransitionRampDownShortSharp1_100to0 => {
+                "Transition Ramp Down Short Sharp 1 100 to 0%"
+            }
+            Effect::TransitionRampDownShortSharp2_100to0 => {
+                "Transition Ramp Down Short Sharp 2 100 to 0%"
+            }
+            Effect::TransitionRampUpLongSmooth1_0to100 => {
+                "Transition Ramp Up Long Smooth 1 0 to 100%"
+            }
+            Effect::TransitionRampUpLongSmooth2_0to100 => {
+                "Transition Ramp Up Long Smooth 2 0 to 100%"
+            }
+            Effect::TransitionRampUpMediumSmooth1_0to100 => {
+                "Transition Ramp Up Medium Smooth 1 0 to 100%"
+            }
+            Effect::TransitionRampUpMediumSmooth2_0to100 => {
+                "Transition Ramp Up Medium Smooth 2 0 to 100%"
+            }
+            Effect::TransitionRampUpShortSmooth1_0to100 => {
+                "Transition Ramp Up Short Smooth 1 0 to 100%"
+            }
+            Effect::TransitionRampUpShortSmooth2_0to100 => {
+                "Transition Ramp Up Short Smooth 2 0 to 100%"
+            }
+            Effect::TransitionRampUpLongSharp1_0to100 => {
+                "Transition Ramp Up Long Sharp 1 0 to 100%"
+            }
+            Effect::TransitionRampUpLongSharp2_0to100 => {
+                "Transition Ramp Up Long Sharp 2 0 to 100%"
+            }
+            Effect::TransitionRampUpMediumSharp1_0to100 => {
+                "Transition Ramp Up Medium Sharp 1 0 to 100%"
+            }
+            Effect::TransitionRampUpMediumSharp2_0to100 => {
+                "Transition Ramp Up Medium Sharp 2 0 to 100%"
+            }
+            Effect::TransitionRampUpShortSharp1_0to100 => {
+                "Transition Ramp Up Short Sharp 1 0 to 100%"
+            }
+            Effect::TransitionRampUpShortSharp2_0to100 => {
+                "Transition Ramp Up Short Sharp 2 0 to 100%"
+            }
+            Effect::TransitionRampDownLongSmooth1_50to0 => {
+                "Transition Ramp Down Long Smooth 1 50 to 0%"
+            }
+            Effect::TransitionRampDownLongSmooth2_50to0 => {
+                "Transition Ramp Down Long Smooth 2 50 to 0%"
+            }
+            Effect::TransitionRampDownMediumSmooth1_50to0 => {
+                "Transition Ramp Down Medium Smooth 1 50 to 0%"
+            }
+            Effect::TransitionRampDownMediumSmooth2_50to0 => {
+                "Transition Ramp Down Medium Smooth 2 50 to 0%"
+            }
+            Effect::TransitionRampDownShortSmooth1_50to0 => {
+                "Transition Ramp Down Short Smooth 1 50 to 0%"
+            }
+            Effect::TransitionRampDownShortSmooth2_50to0 => {
+                "Transition Ramp Down Short Smooth 2 50 to 0%"
+            }
+            Effect::TransitionRampDownLongSharp1_50to0 => {
+                "Transition Ramp Down Long Sharp 1 50 to 0%"
+            }
+            Effect::TransitionRampDownLongSharp2_50to0 => {
+                "Transition Ramp Down Long Sharp 2 50 to 0%"
+            }
+            Effect::TransitionRampDownMediumSharp1_50to0 => {
+                "Transition Ramp Down Medium Sharp 1 50 to 0%"
+            }
+            Effect::TransitionRampDownMediumSharp2_50to0 => {
+                "Transition Ramp Down Medium Sharp 2 50 to 0%"
+            }
+            Effect::TransitionRampDownShortSharp1_50to0 => {
+                "Transition Ramp Down Short Sharp 1 50 to 0%"
+            }
+            Effect::TransitionRampDownShortSharp2_50to0 => {
+                "Transition Ramp Down Short Sharp 2 50 to 0%"
+            }
+            Effect::TransitionRampUpLongSmooth1_0to50 => {
+                "Transition Ramp Up Long Smooth 1 0 to 50%"
+            }
+            Effect::TransitionRampUpLongSmooth2_0to50 => {
+                "Transition Ramp Up Long Smooth 2 0 to 50%"
+            }
+            Effect::TransitionRampUpMediumSmooth1_0to50 => {
+                "Transition Ramp Up Medium Smooth 1 0 to 50%"
+            }
+            Effect::TransitionRampUpMediumSmooth2_0to50 => {
+                "Transition Ramp Up Medium Smooth 2 0 to 50%"
+            }
+            Effect::TransitionRampUpShortSmooth1_0to50 => {
+                "Transition Ramp Up Short Smooth 1 0 to 50%"
+            }
+            Effect::TransitionRampUpShortSmooth2_0to50 => {
+                "Transition Ramp Up Short Smooth 2 0 to 50%"
+            }
+            Effect::TransitionRampUpLongSharp1_0to50 => "Transition Ramp Up Long Sharp 1 0 to 50%",
+            Effect::TransitionRampUpLongSharp2_0to50 => "Transition Ramp Up Long Sharp 2 0 to 50%",
+            Effect::TransitionRampUpMediumSharp1_0to50 => {
+                "Transition Ramp Up Medium Sharp 1 0 to 50%"
+            }
+            Effect::TransitionRampUpMediumSharp2_0to50 => {
+                "Transition Ramp Up Medium Sharp 2 0 to 50%"
+            }
+            Effect::TransitionRampUpShortSharp1_0to50 => {
+                "Transition Ramp Up Short Sharp 1 0 to 50%"
+            }
+            Effect::TransitionRampUpShortSharp2_0to50 => {
+                "Transition Ramp Up Short Sharp 2 0 to 50%"
+            }
+            Effect::LongBuzzForProgrammaticStopping100 => {
+                "Long Buzz For Programmatic Stopping 100%"
+            }
+            Effect::SmoothHum1_50 => "Smooth Hum 1 (No kick or brake pulse) 50%",
+            Effect::SmoothHum2_40 => "Smooth Hum 2 (No kick or brake pulse) 40%",
+            Effect::SmoothHum3_30 => "Smooth Hum 3 (No kick or brake pulse) 30%",
+            Effect::SmoothHum4_20 => "Smooth Hum 4 (No kick or brake pulse) 20%",
+            Effect::SmoothHum5_10 => "Smooth Hum 5 (No kick or brake pulse) 10%",
+        }
+    }
+}
+
+/// Effect ID ranges of the same effect at decreasing intensity
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+const INTENSITY_FAMILIES: [(u8, u8); 20] = [
+    (1, 3),     // Strong Click 100/60/30%
+    (4, 6),     // Sharp Click 100/60/30%
+    (7, 9),     // Soft Bump 100/60/30%
+    (10, 11),   // Double Click 100/60%
+    (17, 20),   // Strong Click 1-4
+    (21, 23),   // Medium Click 1-3
+    (24, 26),   // Sharp Tick 1-3
+    (27, 30),   // Short Double Click Strong 1-4
+    (31, 33),   // Short Double Click Medium 1-3
+    (34, 36),   // Short Double Sharp Tick 1-3
+    (37, 40),   // Long Double Sharp Click Strong 1-4
+    (41, 43),   // Long Double Sharp Click Medium 1-3
+    (44, 46),   // Long Double Sharp Tick 1-3
+    (47, 51),   // Buzz 1-5
+    (52, 53),   // Pulsing Strong 1-2
+    (54, 55),   // Pulsing Medium 1-2
+    (56, 57),   // Pulsing Sharp 1-2
+    (58, 63),   // Transition Click 1-6
+    (64, 69),   // Transition Hum 1-6
+    (119, 123), // Smooth Hum 1-5
+];
+
+/// Peak intensity in percent of each ROM library effect, indexed by effect ID - 1
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+const EFFECT_INTENSITY: [u8; 123] = [
+    100, 60, 30, 100, 60, 30, 100, 60, 30, 100, 60, 100, 60, 100, 100, 100, 100, 80, 60, 30, 100,
+    80, 60, 100, 80, 60, 100, 80, 60, 30, 100, 80, 60, 100, 80, 60, 100, 80, 60, 30, 100, 80, 60,
+    100, 80, 60, 100, 80, 60, 40, 20, 100, 60, 100, 60, 100, 60, 100, 80, 60, 40, 20, 10, 100, 80,
+    60, 40, 20, 10, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100,
+    100, 100, 100, 100, 100, 100, 100, 100, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50,
+    50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 100, 50, 40, 30, 20, 10,
+];
+
+/// Category of a ROM library effect, see [`Effect::category`]
+///
+/// Only available on DRV2605 and DRV2605L variants which have a ROM library.
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EffectCategory {
+    /// Clicks, ticks and bumps, including double and triple clicks
+    Click,
+    /// Buzzes, including soft fuzz and the long buzz for programmatic stopping
+    Buzz,
+    /// 750 ms and 1000 ms alerts
+    Alert,
+    /// Pulsing effects
+    Pulsing,
+    /// Transition clicks and hums
+    Transition,
+    /// Transition ramps down
+    RampDown,
+    /// Transition ramps up
+    RampUp,
+    /// Smooth hums without kick or brake pulse
+    Hum,
+}
+
+/// Approximate nominal duration of a ROM library effect ID in milliseconds (0 if unknown)
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+fn effect_duration_ms(id: u8) -> u16 {
+    match id {
+        // Strong clicks, sharp clicks, soft bumps
+        1..=3 => 60,
+        4..=6 => 40,
+        7..=9 => 80,
+        // Double/triple click, soft fuzz, strong buzz
+        10..=11 => 180,
+        12 => 300,
+        13 => 200,
+        14 => 300,
+        // Alerts
+        15 => 750,
+        16 => 1000,
+        // Strong clicks, medium clicks, sharp ticks
+        17..=20 => 60,
+        21..=23 => 50,
+        24..=26 => 30,
+        // Short double clicks and ticks
+        27..=33 => 150,
+        34..=36 => 100,
+        // Long double sharp clicks and ticks
+        37..=43 => 250,
+        44..=46 => 200,
+        // Buzzes, pulsing
+        47..=51 => 300,
+        52..=57 => 500,
+        // Transition clicks and hums
+        58..=63 => 80,
+        64..=69 => 400,
+        // Transition ramps, in groups of long/medium/short pairs
+        70..=117 => match (id - 70) % 6 / 2 {
+            0 => 800,
+            1 => 500,
+            _ => 250,
+        },
+        // Long buzz for programmatic stopping
+        118 => 1500,
+        // Smooth hums
+        119..=123 => 400,
+        _ => 0,
+    }
+}
+
+/// Estimate the playback duration of a waveform sequence in milliseconds
+///
+/// Sums the nominal durations of the effects (see [`Effect::duration_ms`]) and the wait
+/// times up to the first stop entry, saturating at `u16::MAX`. This is an estimate based
+/// on nominal ROM library durations; actual playback time depends on the library,
+/// actuator and timing offsets. No bus access is involved.
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+pub fn sequence_duration_ms(entries: &[WaveformEntry]) -> u16 {
+    entries
+        .iter()
+        .take_while(|entry| !entry.is_stop())
+        .map(|entry| {
+            if entry.is_wait {
+                u16::from(entry.value) * 10
+            } else {
+                effect_duration_ms(entry.value)
+            }
+        })
+        .fold(0, u16::saturating_add)
+}
+
+/// Error returned by the checked [`WaveformEntry`] constructors for out-of-range values
+///
+/// Independent of the I2C bus, so entries can be built before a driver exists; it converts
+/// into `Error::InvalidWaveform` with `?`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct InvalidWaveform;
+
 /// Waveform sequencer entry
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WaveformEntry {
     /// Waveform sequence value (0-127) or wait time if wait flag is set
     pub value: u8,
@@ -276,6 +865,9 @@ pub struct WaveformEntry {
 
 impl WaveformEntry {
     /// Create a new waveform entry for an effect using effect ID
+    ///
+    /// Effect ID 0 is reserved: it produces the same entry as [`stop`](Self::stop) and ends
+    /// the sequence.
     pub fn effect(effect_id: u8) -> Self {
         Self {
             value: effect_id & 0x7F,
@@ -283,6 +875,17 @@ impl WaveformEntry {
         }
     }
 
+    /// Create a new waveform entry, rejecting values that do not fit in 7 bits
+    ///
+    /// Unlike [`effect`](Self::effect), which masks `effect_id` to 7 bits (so 200 becomes
+    /// 72), this returns [`InvalidWaveform`] for values above 127.
+    pub fn try_effect(effect_id: u8) -> Result<Self, InvalidWaveform> {
+        if effect_id > 0x7F {
+            return Err(InvalidWaveform);
+        }
+        Ok(Self::effect(effect_id))
+    }
+
     /// Create a new waveform entry for a predefined effect
     ///
     /// Only available on DRV2605 and DRV2605L variants which have a ROM library.
@@ -302,6 +905,40 @@ impl WaveformEntry {
         }
     }
 
+    /// Create a new wait entry, rejecting wait times that do not fit in 7 bits
+    ///
+    /// Unlike [`wait`](Self::wait), which masks the wait time to 7 bits, this returns
+    /// [`InvalidWaveform`] for values above 127 (1.27 s).
+    pub fn try_wait(wait_time_10ms: u8) -> Result<Self, InvalidWaveform> {
+        if wait_time_10ms > 0x7F {
+            return Err(InvalidWaveform);
+        }
+        Ok(Self::wait(wait_time_10ms))
+    }
+
+    /// Maximum wait time representable by a wait entry, in milliseconds
+    pub const MAX_WAIT_MS: u16 = 1270;
+
+    /// Create a new wait entry from a duration in milliseconds
+    ///
+    /// The duration is rounded to the nearest 10ms unit. Returns [`InvalidWaveform`] if it
+    /// exceeds [`MAX_WAIT_MS`](Self::MAX_WAIT_MS).
+    pub fn wait_ms(ms: u16) -> Result<Self, InvalidWaveform> {
+        if ms > Self::MAX_WAIT_MS {
+            return Err(InvalidWaveform);
+        }
+        Ok(Self::wait_from_ms(ms))
+    }
+
+    /// Create a new wait entry from a duration in milliseconds, saturating at
+    /// [`MAX_WAIT_MS`](Self::MAX_WAIT_MS)
+    ///
+    /// The duration is rounded to the nearest 10ms unit.
+    pub fn wait_from_ms(ms: u16) -> Self {
+        let units = (ms.min(Self::MAX_WAIT_MS) + 5) / 10;
+        Self::wait(units as u8)
+    }
+
     /// Create a stop entry (terminates sequence)
     pub fn stop() -> Self {
         Self {
@@ -309,6 +946,109 @@ impl WaveformEntry {
             is_wait: false,
         }
     }
+
+    /// Check whether this entry is a stop entry (terminates sequence)
+    pub fn is_stop(&self) -> bool {
+        !self.is_wait && self.value == 0
+    }
+
+    /// Highest effect ID in the ROM libraries
+    pub const MAX_EFFECT_ID: u8 = 123;
+}
+
+impl WaveformEntry {
+    /// Encode the entry as a WAV_FRM_SEQ register byte
+    pub(crate) fn to_register(self) -> u8 {
+        (self.value & 0x7F) | (u8::from(self.is_wait) << 7)
+    }
+
+    /// Decode a WAV_FRM_SEQ register byte
+    pub(crate) fn from_register(raw: u8) -> Self {
+        Self {
+            value: raw & 0x7F,
+            is_wait: raw & 0x80 != 0,
+        }
+    }
+}
+
+/// Encode waveform entries as the eight sequencer register values
+///
+/// Produces the exact bytes `set_waveform_sequence` writes to WAV_FRM_SEQ1..8: the effect
+/// ID or wait time in bits 0-6 and the wait flag in bit 7, with unused slots set to 0
+/// (stop). Entries beyond the 8 slots are ignored. No bus access is involved, so this is
+/// suitable for logging or comparing haptic configurations.
+pub fn encode_sequence(entries: &[WaveformEntry]) -> [u8; SEQUENCER_SLOTS] {
+    let mut bytes = [WaveformEntry::stop().to_register(); SEQUENCER_SLOTS];
+    for (byte, entry) in bytes.iter_mut().zip(entries) {
+        *byte = entry.to_register();
+    }
+    bytes
+}
+
+/// Pack all sequencer slots into the little-endian sequencer block value
+pub(crate) fn pack_slots(slots: &[WaveformEntry; SEQUENCER_SLOTS]) -> u64 {
+    u64::from_le_bytes(encode_sequence(slots))
+}
+
+/// Unpack the little-endian sequencer block value into sequencer slots
+pub(crate) fn unpack_slots(raw: u64, out: &mut [WaveformEntry; SEQUENCER_SLOTS]) {
+    for (entry, byte) in out.iter_mut().zip(raw.to_le_bytes()) {
+        *entry = WaveformEntry::from_register(byte);
+    }
+}
+
+/// Interleave effects with wait entries of `gap_ms` into the sequencer slots
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+pub(crate) fn interleave_gaps<E>(
+    effects: &[Effect],
+    gap_ms: u16,
+) -> Result<[WaveformEntry; SEQUENCER_SLOTS], Error<E>> {
+    let gap = WaveformEntry::wait_ms(gap_ms)?;
+    let mut slots = [WaveformEntry::stop(); SEQUENCER_SLOTS];
+    let mut len = 0;
+    let mut push = |entry: WaveformEntry| -> Result<(), Error<E>> {
+        *slots.get_mut(len).ok_or(Error::InvalidWaveform)? = entry;
+        len += 1;
+        Ok(())
+    };
+
+    for (i, &effect) in effects.iter().enumerate() {
+        if i > 0 && gap.value != 0 {
+            push(gap)?;
+        }
+        push(effect.into())?;
+    }
+    Ok(slots)
+}
+
+/// Check whether a stop entry is followed by non-stop entries that would never play
+pub(crate) fn truncates_early(entries: &[WaveformEntry]) -> bool {
+    entries
+        .iter()
+        .skip_while(|entry| !entry.is_stop())
+        .any(|entry| !entry.is_stop())
+}
+
+/// Number of entries before the first stop entry
+pub(crate) fn playable_len(entries: &[WaveformEntry]) -> usize {
+    entries
+        .iter()
+        .position(|entry| entry.is_stop())
+        .unwrap_or(entries.len())
+}
+
+/// Validate the effect IDs of a waveform sequence
+///
+/// Effect entries must be in `1..=MAX_EFFECT_ID`, and a stop entry may only be followed by
+/// further stop entries, since anything after it would never play.
+pub(crate) fn validate_sequence<E>(entries: &[WaveformEntry]) -> Result<(), Error<E>> {
+    let out_of_range = entries
+        .iter()
+        .any(|entry| !entry.is_wait && entry.value > WaveformEntry::MAX_EFFECT_ID);
+    if out_of_range || truncates_early(entries) {
+        return Err(Error::InvalidWaveform);
+    }
+    Ok(())
 }
 
 #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
@@ -317,3 +1057,441 @@ impl From<Effect> for WaveformEntry {
         Self::effect_from_enum(effect)
     }
 }
+
+/// Fixed-capacity waveform sequence validated at compile time
+///
+/// `N` is the number of entries and must not exceed the 8 hardware sequencer slots; this is
+/// checked when the sequence is constructed, so writing it to the device never hits the
+/// runtime `InvalidWaveform` length check.
+///
+/// ```rust,ignore
+/// let sequence = Sequence::new([
+///     WaveformEntry::effect(1),
+///     WaveformEntry::wait(5),
+///     WaveformEntry::effect(1),
+/// ]);
+/// haptic.set_sequence(&sequence)?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Sequence<const N: usize> {
+    entries: [WaveformEntry; N],
+}
+
+impl<const N: usize> Sequence<N> {
+    const CAPACITY_CHECK: () = assert!(
+        N <= SEQUENCER_SLOTS,
+        "Sequence capacity exceeds the 8 sequencer slots"
+    );
+
+    /// Create a new sequence from exactly `N` entries
+    pub fn new(entries: [WaveformEntry; N]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAPACITY_CHECK;
+        Self { entries }
+    }
+
+    /// Get the entries of the sequence
+    pub fn entries(&self) -> &[WaveformEntry; N] {
+        &self.entries
+    }
+
+    /// Expand the sequence to all 8 sequencer slots, padding with stop entries
+    pub fn to_slots(&self) -> [WaveformEntry; SEQUENCER_SLOTS] {
+        let mut slots = [WaveformEntry::stop(); SEQUENCER_SLOTS];
+        slots[..N].copy_from_slice(&self.entries);
+        slots
+    }
+}
+
+impl<const N: usize> From<[WaveformEntry; N]> for Sequence<N> {
+    fn from(entries: [WaveformEntry; N]) -> Self {
+        Self::new(entries)
+    }
+}
+
+/// Growable waveform sequence for composing sequences from reusable parts
+///
+/// Holds up to the 8 hardware sequencer slots; pushing past that returns
+/// `Error::InvalidWaveform` and leaves the buffer unchanged. Load it with
+/// `set_waveform_sequence(buf.entries())`.
+///
+/// ```rust,ignore
+/// let mut buf = SequenceBuf::new();
+/// buf.extend_from_slice(&tap)?;
+/// buf.push(WaveformEntry::wait(10))?;
+/// buf.extend_from_slice(&tap)?;
+/// haptic.set_waveform_sequence(buf.entries())?;
+/// ```
+///
+/// Only available with the `heapless` feature.
+#[cfg(feature = "heapless")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct SequenceBuf {
+    entries: heapless::Vec<WaveformEntry, SEQUENCER_SLOTS>,
+}
+
+#[cfg(feature = "heapless")]
+impl SequenceBuf {
+    /// Create an empty sequence buffer
+    pub const fn new() -> Self {
+        Self {
+            entries: heapless::Vec::new(),
+        }
+    }
+
+    /// Append one entry, failing if all 8 slots are in use
+    pub fn push<E>(&mut self, entry: WaveformEntry) -> Result<(), Error<E>> {
+        self.entries.push(entry).map_err(|_| Error::InvalidWaveform)
+    }
+
+    /// Append all `entries`, or none of them if they do not fit
+    pub fn extend_from_slice<E>(&mut self, entries: &[WaveformEntry]) -> Result<(), Error<E>> {
+        self.entries
+            .extend_from_slice(entries)
+            .map_err(|_| Error::InvalidWaveform)
+    }
+
+    /// Remove all entries
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Get the entries pushed so far
+    pub fn entries(&self) -> &[WaveformEntry] {
+        &self.entries
+    }
+
+    /// Get the number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the buffer is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Expand the buffer to all 8 sequencer slots, padding with stop entries
+    pub fn to_slots(&self) -> [WaveformEntry; SEQUENCER_SLOTS] {
+        let mut slots = [WaveformEntry::stop(); SEQUENCER_SLOTS];
+        slots[..self.entries.len()].copy_from_slice(&self.entries);
+        slots
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> From<Sequence<N>> for SequenceBuf {
+    fn from(sequence: Sequence<N>) -> Self {
+        let mut entries = heapless::Vec::new();
+        // Sequence<N> guarantees N <= SEQUENCER_SLOTS, so this cannot fail
+        let _ = entries.extend_from_slice(sequence.entries());
+        Self { entries }
+    }
+}
+
+/// Ready-to-play sequences for common UI haptic idioms
+///
+/// Each function returns a [`Sequence`] that can be loaded with `set_sequence()`:
+///
+/// ```rust,ignore
+/// use drv260x::effects::patterns;
+///
+/// haptic.set_sequence(&patterns::double_tap(Effect::SharpClick100, 80))?;
+/// haptic.go()?;
+/// ```
+///
+/// Only available on DRV2605 and DRV2605L variants which have a ROM library.
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+pub mod patterns {
+    use super::{Effect, Sequence, WaveformEntry, INTENSITY_FAMILIES};
+    use crate::SEQUENCER_SLOTS;
+
+    /// Play an effect twice with a gap of `gap_ms` milliseconds in between
+    ///
+    /// The gap is rounded to 10ms units and saturates at [`WaveformEntry::MAX_WAIT_MS`].
+    pub fn double_tap(effect: Effect, gap_ms: u16) -> Sequence<3> {
+        let gap = WaveformEntry::wait_from_ms(gap_ms);
+        Sequence::new([effect.into(), gap, effect.into()])
+    }
+
+    /// Play an effect three times with a gap of `gap_ms` milliseconds between each
+    ///
+    /// The gap is rounded to 10ms units and saturates at [`WaveformEntry::MAX_WAIT_MS`].
+    pub fn triple_tap(effect: Effect, gap_ms: u16) -> Sequence<5> {
+        let gap = WaveformEntry::wait_from_ms(gap_ms);
+        Sequence::new([effect.into(), gap, effect.into(), gap, effect.into()])
+    }
+
+    /// Play a heartbeat: a strong beat followed shortly by a weaker one
+    pub fn heartbeat() -> Sequence<3> {
+        Sequence::new([
+            Effect::StrongClick100.into(),
+            WaveformEntry::wait_from_ms(120),
+            Effect::StrongClick60.into(),
+        ])
+    }
+
+    /// Play an effect followed by its lower-intensity variants
+    ///
+    /// For example `StrongClick100` yields Strong Click 100%, 60% and 30%. The remaining
+    /// slots are filled with stop entries. Returns `None` if the effect has no
+    /// lower-intensity variant in the ROM library.
+    pub fn ramp_down_sequence(base: Effect) -> Option<[WaveformEntry; SEQUENCER_SLOTS]> {
+        let id = base as u8;
+        let &(_, last) = INTENSITY_FAMILIES
+            .iter()
+            .find(|&&(first, last)| (first..=last).contains(&id))?;
+        if id == last {
+            return None;
+        }
+
+        let mut slots = [WaveformEntry::stop(); SEQUENCER_SLOTS];
+        for (slot, effect_id) in slots.iter_mut().zip(id..=last) {
+            *slot = WaveformEntry::effect(effect_id);
+        }
+        Some(slots)
+    }
+
+    /// Play an effect followed by a pause of the same length as the effect
+    ///
+    /// The sequencer always lets an effect finish before moving to the next slot, so the
+    /// wait entry is pure silence after it. The pause is taken from
+    /// [`Effect::duration_ms`] (rounded to 10ms units, at most 1.27s), which gives long
+    /// effects like [`Effect::Alert750ms`] an even on/off cadence when the sequence is
+    /// chained or repeated.
+    pub fn with_matching_pause(effect: Effect) -> Sequence<2> {
+        Sequence::new([
+            effect.into(),
+            WaveformEntry::wait_from_ms(effect.duration_ms()),
+        ])
+    }
+
+    /// Play a short tick followed by a buzz, e.g. for a long-press confirmation
+    pub fn tick_then_buzz() -> Sequence<3> {
+        Sequence::new([
+            Effect::SharpTick1_100.into(),
+            WaveformEntry::wait_from_ms(50),
+            Effect::Buzz1_100.into(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_sequence_sets_wait_bit_and_pads_with_stops() {
+        let entries = [
+            WaveformEntry::effect(1),
+            WaveformEntry::wait(5),
+            WaveformEntry::effect(47),
+        ];
+        assert_eq!(
+            encode_sequence(&entries),
+            [0x01, 0x85, 0x2F, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn encode_sequence_ignores_entries_beyond_eight_slots() {
+        let entries = [WaveformEntry::wait(127); SEQUENCER_SLOTS + 1];
+        assert_eq!(encode_sequence(&entries), [0xFF; SEQUENCER_SLOTS]);
+    }
+
+    #[test]
+    fn register_byte_round_trips() {
+        for raw in 0..=u8::MAX {
+            assert_eq!(WaveformEntry::from_register(raw).to_register(), raw);
+        }
+        assert_eq!(WaveformEntry::from_register(0x85), WaveformEntry::wait(5));
+    }
+
+    #[test]
+    fn try_effect_accepts_seven_bit_ids_only() {
+        assert_eq!(
+            WaveformEntry::try_effect(127),
+            Ok(WaveformEntry::effect(127))
+        );
+        assert_eq!(WaveformEntry::try_effect(128), Err(InvalidWaveform));
+    }
+
+    #[test]
+    fn try_wait_accepts_seven_bit_times_only() {
+        assert_eq!(WaveformEntry::try_wait(127), Ok(WaveformEntry::wait(127)));
+        assert_eq!(WaveformEntry::try_wait(128), Err(InvalidWaveform));
+    }
+
+    #[test]
+    fn wait_ms_rejects_times_beyond_max_wait() {
+        assert_eq!(
+            WaveformEntry::wait_ms(WaveformEntry::MAX_WAIT_MS),
+            Ok(WaveformEntry::wait(127))
+        );
+        assert_eq!(
+            WaveformEntry::wait_ms(WaveformEntry::MAX_WAIT_MS + 1),
+            Err(InvalidWaveform)
+        );
+        let error: Error<()> = InvalidWaveform.into();
+        assert!(matches!(error, Error::InvalidWaveform));
+    }
+
+    #[test]
+    fn sequencer_block_round_trips() {
+        let slots = [
+            WaveformEntry::effect(1),
+            WaveformEntry::wait(5),
+            WaveformEntry::effect(123),
+            WaveformEntry::wait(127),
+            WaveformEntry::stop(),
+            WaveformEntry::stop(),
+            WaveformEntry::stop(),
+            WaveformEntry::stop(),
+        ];
+        let mut out = [WaveformEntry::stop(); SEQUENCER_SLOTS];
+        unpack_slots(pack_slots(&slots), &mut out);
+        assert_eq!(out, slots);
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn category_boundaries() {
+        let expected = [
+            (Effect::StrongClick100, EffectCategory::Click),
+            (Effect::SoftFuzz60, EffectCategory::Buzz),
+            (Effect::StrongBuzz100, EffectCategory::Buzz),
+            (Effect::Alert750ms, EffectCategory::Alert),
+            (Effect::Alert1000ms, EffectCategory::Alert),
+            (Effect::StrongClick1_100, EffectCategory::Click),
+            (Effect::Buzz1_100, EffectCategory::Buzz),
+            (Effect::PulsingStrong1_100, EffectCategory::Pulsing),
+            (Effect::TransitionClick1_100, EffectCategory::Transition),
+            (Effect::TransitionHum6_10, EffectCategory::Transition),
+            (
+                Effect::TransitionRampDownLongSmooth1_100to0,
+                EffectCategory::RampDown,
+            ),
+            (
+                Effect::TransitionRampUpShortSharp2_0to100,
+                EffectCategory::RampUp,
+            ),
+            (
+                Effect::TransitionRampDownLongSmooth1_50to0,
+                EffectCategory::RampDown,
+            ),
+            (
+                Effect::TransitionRampUpShortSharp2_0to50,
+                EffectCategory::RampUp,
+            ),
+            (
+                Effect::LongBuzzForProgrammaticStopping100,
+                EffectCategory::Buzz,
+            ),
+            (Effect::SmoothHum1_50, EffectCategory::Hum),
+            (Effect::SmoothHum5_10, EffectCategory::Hum),
+        ];
+        for (effect, category) in expected {
+            assert_eq!(effect.category(), category, "{effect:?}");
+        }
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn ramps_split_evenly_into_up_and_down() {
+        let count = |category: EffectCategory| {
+            Effect::all()
+                .filter(|effect| effect.category() == category)
+                .count()
+        };
+        assert_eq!(count(EffectCategory::RampDown), 24);
+        assert_eq!(count(EffectCategory::RampUp), 24);
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn find_filters_by_category_and_intensity() {
+        let clicks: Vec<Effect> = Effect::find(EffectCategory::Click, 100).collect();
+        assert!(clicks.contains(&Effect::StrongClick100));
+        assert!(clicks.contains(&Effect::SharpClick100));
+        assert!(!clicks.contains(&Effect::StrongClick60));
+        assert!(clicks
+            .iter()
+            .all(|e| e.category() == EffectCategory::Click && e.intensity() == 100));
+
+        let alerts: Vec<Effect> = Effect::find(EffectCategory::Alert, 0).collect();
+        assert_eq!(alerts, [Effect::Alert750ms, Effect::Alert1000ms]);
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn find_above_full_intensity_is_empty() {
+        assert_eq!(Effect::find(EffectCategory::Click, 101).count(), 0);
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn nearest_intensity_picks_closest_family_member() {
+        let base = Effect::StrongClick100;
+        assert_eq!(Effect::nearest_intensity(base, 100), Effect::StrongClick100);
+        assert_eq!(Effect::nearest_intensity(base, 70), Effect::StrongClick60);
+        assert_eq!(Effect::nearest_intensity(base, 0), Effect::StrongClick30);
+        // Any member of the family works as the base
+        assert_eq!(
+            Effect::nearest_intensity(Effect::StrongClick30, 255),
+            Effect::StrongClick100
+        );
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn nearest_intensity_prefers_stronger_variant_on_tie() {
+        // 45% is 15 away from both 60% and 30%
+        assert_eq!(
+            Effect::nearest_intensity(Effect::StrongClick100, 45),
+            Effect::StrongClick60
+        );
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn nearest_intensity_keeps_effects_without_variants() {
+        assert_eq!(
+            Effect::nearest_intensity(Effect::Alert750ms, 10),
+            Effect::Alert750ms
+        );
+        assert_eq!(
+            Effect::nearest_intensity(Effect::LongBuzzForProgrammaticStopping100, 10),
+            Effect::LongBuzzForProgrammaticStopping100
+        );
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn recommended_brake_factor_per_category() {
+        let expected = [
+            (Effect::StrongClick100, Some(FbBrakeFactor::X4)),
+            (
+                Effect::TransitionRampUpLongSmooth1_0to100,
+                Some(FbBrakeFactor::X2),
+            ),
+            (
+                Effect::TransitionRampDownLongSmooth1_100to0,
+                Some(FbBrakeFactor::Disabled),
+            ),
+            (Effect::SmoothHum1_50, Some(FbBrakeFactor::Disabled)),
+            (Effect::Buzz1_100, None),
+            (Effect::Alert750ms, None),
+            (Effect::PulsingStrong1_100, None),
+            (Effect::TransitionClick1_100, None),
+        ];
+        for (effect, brake_factor) in expected {
+            assert_eq!(
+                effect.recommended_brake_factor(),
+                brake_factor,
+                "{effect:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Enable (EN) pin management
+//!
+//! Boards that gate the DRV260X EN pin from a GPIO can wrap the driver in a
+//! [`Drv260xWithEnable`], which owns the pin and waits for the device to become ready on
+//! the bus after enabling it:
+//!
+//! ```rust,ignore
+//! use drv260x::{Drv260x, Drv260xWithEnable};
+//!
+//! let mut haptic = Drv260xWithEnable::new(Drv260x::new(i2c), en_pin);
+//! haptic.enable(&mut delay)?;
+//! haptic.driver_mut().init()?;
+//! ```
+//!
+//! Boards that tie EN high keep using [`Drv260x`] directly.
+
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::OutputPin;
+
+use crate::{ll, Drv260x};
+
+/// Time after EN is driven high before the device accepts I2C transactions
+///
+/// This is the datasheet wake-up time that [`ll::STANDBY_EXIT_US`] is based on.
+pub const EN_SETTLE_TIME_US: u32 = ll::STANDBY_EXIT_US;
+
+/// DRV260X driver together with the GPIO driving its EN pin
+pub struct Drv260xWithEnable<I2C, EN> {
+    driver: Drv260x<I2C>,
+    en: EN,
+}
+
+impl<I2C, EN: OutputPin> Drv260xWithEnable<I2C, EN> {
+    /// Wrap a driver and the output pin connected to its EN pin
+    ///
+    /// The pin is not touched until `enable()` or `disable()` is called.
+    pub fn new(driver: Drv260x<I2C>, en: EN) -> Self {
+        Self { driver, en }
+    }
+
+    /// Drive EN high and wait until the device accepts I2C transactions
+    pub fn enable(&mut self, delay: &mut impl DelayNs) -> Result<(), EN::Error> {
+        self.en.set_high()?;
+        delay.delay_us(EN_SETTLE_TIME_US);
+        Ok(())
+    }
+
+    /// Drive EN high and wait until the device accepts I2C transactions (async version)
+    #[cfg(feature = "async")]
+    pub async fn enable_async(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> Result<(), EN::Error> {
+        self.en.set_high()?;
+        delay.delay_us(EN_SETTLE_TIME_US).await;
+        Ok(())
+    }
+
+    /// Drive EN low, putting the device into shutdown
+    ///
+    /// The device does not respond on the bus while disabled.
+    pub fn disable(&mut self) -> Result<(), EN::Error> {
+        self.en.set_low()
+    }
+
+    /// Get a reference to the wrapped driver
+    pub fn driver(&self) -> &Drv260x<I2C> {
+        &self.driver
+    }
+
+    /// Get a mutable reference to the wrapped driver
+    pub fn driver_mut(&mut self) -> &mut Drv260x<I2C> {
+        &mut self.driver
+    }
+
+    /// Release the driver and the EN pin
+    pub fn release(self) -> (Drv260x<I2C>, EN) {
+        (self.driver, self.en)
+    }
+}
@@ -53,9 +53,28 @@
 //! drv260x = { version = "0.1", features = ["async"] }
 //! ```
 //!
+//! ### Mixing blocking and async
+//!
+//! [`Drv260x`] places no trait bounds on its bus type; the blocking methods are available
+//! when it implements `embedded_hal::i2c::I2c` and the `_async` methods when it implements
+//! `embedded_hal_async::i2c::I2c`. With a HAL bus type implementing both, the same driver
+//! instance can be initialized with blocking calls and then used from async code, without
+//! any conversion:
+//!
+//! ```rust,ignore
+//! let mut haptic = Drv260x::new(i2c);
+//! haptic.init()?; // blocking, e.g. before the executor starts
+//!
+//! // later, inside an async task
+//! haptic.set_single_effect_async(1).await?;
+//! haptic.go_async().await?;
+//! ```
+//!
+//! Driver state such as the detected variant and cached mode is shared between both APIs.
+//!
 //! [`embedded-hal`]: https://crates.io/crates/embedded-hal
 
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 #![deny(missing_docs)]
 
 #[cfg(not(any(
@@ -71,32 +90,201 @@ compile_error!(
 // Module declarations
 #[cfg(feature = "async")]
 mod async_impl;
+pub mod config;
 pub mod effects;
+pub mod enable;
 pub mod ll;
+#[cfg(any(feature = "drv2604", feature = "drv2604l"))]
+pub mod ram;
+#[cfg(feature = "serde")]
+mod serde_remote;
 mod sync_impl;
 
 // Re-export the low-level types from ll module
 pub use ll::{
-    AutoCalibTime, AutoOpenLoopCnt, FbBrakeFactor, LoopGain, NoiseGateThreshold, OperatingMode,
-    SampleTime, ZeroCrossTime,
+    AutoCalibTime, AutoOpenLoopCnt, BemfGain, FbBrakeFactor, LoopGain, NoiseGateThreshold,
+    OperatingMode, SampleTime, ZeroCrossTime,
 };
 
 // Re-export ROM-only types (audio-to-vibe, library selection)
 #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
 pub use ll::{AthFilter, AthPeakTime, LibrarySelection};
 
+// Re-export the cancellation guard from the async module
+#[cfg(feature = "async")]
+pub use async_impl::PlaybackGuard;
+
+// Re-export the configuration and builder types from config module
+pub use config::{Config, Drv260xBuilder};
+
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+pub use config::AudioToVibeConfig;
+
+// Re-export the EN pin wrapper from enable module
+pub use enable::Drv260xWithEnable;
+
 // Re-export the effects and waveform types from effects module
-pub use effects::WaveformEntry;
+pub use effects::{InvalidWaveform, Sequence, WaveformEntry};
 
 #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
-pub use effects::Effect;
+pub use effects::{Effect, EffectCategory};
+
+#[cfg(feature = "heapless")]
+pub use effects::SequenceBuf;
+
+// Re-export the waveform RAM library builder (RAM-only variants)
+#[cfg(any(feature = "drv2604", feature = "drv2604l"))]
+pub use ram::{RamHeaderEntry, RamLibraryBuilder, RamSample};
 
 /// I2C address of the DRV260X family
 pub const I2C_ADDRESS: u8 = ll::I2C_ADDRESS;
 
+/// Number of slots in the hardware waveform sequencer
+pub const SEQUENCER_SLOTS: usize = 8;
+
+/// Interval between GO-bit polls while waiting for playback to complete
+pub(crate) const PLAYBACK_POLL_INTERVAL_MS: u32 = 5;
+
+/// Resolution of the LRA_PERIOD register in microseconds
+pub(crate) const LRA_PERIOD_STEP_US: f32 = 98.46;
+
+/// Maximum time allowed for each self-test step (diagnostics, calibration, test effect)
+pub(crate) const SELF_TEST_STEP_TIMEOUT_US: u32 = 2_000_000;
+
+/// Maximum time to wait for auto-calibration, with margin over [`ll::CALIBRATION_MAX_US`]
+pub(crate) const AUTO_CALIBRATION_TIMEOUT_US: u32 = ll::CALIBRATION_MAX_US + 800_000;
+
+/// Time to wait after DEV_RESET before the device is polled for readiness
+pub(crate) const RESET_TIME_MS: u32 = ll::RESET_SETTLE_US / 1000;
+
+/// Maximum time to wait for the device to respond again after DEV_RESET
+pub(crate) const RESET_TIMEOUT_MS: u32 = 50;
+
+/// Time allowed for OTP programming to complete before OTP_STATUS is checked
+#[cfg(all(feature = "otp", any(feature = "drv2605l", feature = "drv2604l")))]
+pub(crate) const OTP_PROGRAM_TIME_MS: u32 = 100;
+
+/// Convert a time offset in milliseconds to PLAYBACK_INTERVAL register units
+///
+/// Rounds to the nearest unit and saturates to the `i8` register range, so offsets beyond
+/// ±127 intervals are clamped rather than wrapped.
+pub(crate) fn time_offset_units(ms: i16, playback_interval_us: u16) -> i8 {
+    let interval = i32::from(playback_interval_us.max(1));
+    let us = i32::from(ms) * 1000;
+    let half = if us < 0 { -interval / 2 } else { interval / 2 };
+    ((us + half) / interval).clamp(i8::MIN.into(), i8::MAX.into()) as i8
+}
+
+/// Time to wait for an effect of nominal length `duration_ms` before giving up
+///
+/// Allows generous slack (twice the nominal duration plus 500 ms), since the ROM durations
+/// are approximate and vary with the actuator and braking.
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+pub(crate) fn playback_timeout_us(duration_ms: u16) -> u32 {
+    (u32::from(duration_ms) * 2 + 500) * 1000
+}
+
+/// Longest RAM waveform playback that ends on its own: 15 voltage-time pairs of 255 × 5 ms,
+/// played once plus 6 repeats
+#[cfg(not(any(feature = "drv2605", feature = "drv2605l")))]
+const RAM_WAVEFORM_MAX_MS: u32 = 15 * 255 * 5 * 7;
+
+/// Time to wait for a chunk of sequencer entries to finish before giving up
+///
+/// Applies [`playback_timeout_us`] to the estimated [`effects::sequence_duration_ms`].
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+pub(crate) fn sequence_timeout_us(entries: &[WaveformEntry]) -> u32 {
+    playback_timeout_us(effects::sequence_duration_ms(entries))
+}
+
+/// Time to wait for a chunk of sequencer entries to finish before giving up
+///
+/// RAM waveform durations depend on the uploaded library, so each effect entry is allowed
+/// the longest waveform the RAM header can describe, plus the wait times and 500 ms of
+/// slack. Waveforms set to repeat forever never finish and always time out.
+#[cfg(not(any(feature = "drv2605", feature = "drv2605l")))]
+pub(crate) fn sequence_timeout_us(entries: &[WaveformEntry]) -> u32 {
+    let max_ms = entries
+        .iter()
+        .take_while(|entry| !entry.is_stop())
+        .map(|entry| {
+            if entry.is_wait {
+                u32::from(entry.value) * 10
+            } else {
+                RAM_WAVEFORM_MAX_MS
+            }
+        })
+        .fold(500, u32::saturating_add);
+    max_ms.saturating_mul(1000)
+}
+
+/// Reject feedback settings chosen for a different actuator than the device is set up for
+///
+/// The N_ERM_LRA bit of the Feedback Control register (0x1A) selects how BEMF_GAIN is
+/// scaled (see [`BemfGain`]), so a gain tuned for one actuator type is wrong for the other.
+/// The datasheet names no loop gain and brake factor pairing as invalid, so those are not
+/// checked.
+pub(crate) fn check_feedback_actuator<E>(
+    configured_lra: bool,
+    actuator: Actuator,
+) -> Result<(), Error<E>> {
+    if configured_lra != (actuator == Actuator::Lra) {
+        return Err(Error::InvalidConfig(
+            "feedback settings are for the other actuator type",
+        ));
+    }
+    Ok(())
+}
+
+/// Actuator type driven by the device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Actuator {
+    /// Eccentric rotating mass motor
+    Erm,
+    /// Linear resonant actuator
+    Lra,
+}
+
+/// How playback is started in an operating mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum TriggerSource {
+    /// Started by setting the GO bit (`go()`)
+    Go,
+    /// Started by the IN/TRIG pin
+    Pin,
+    /// Driven continuously from the input signal or RTP register; GO has no effect
+    Continuous,
+}
+
+impl OperatingMode {
+    /// Get how playback is started in this mode
+    ///
+    /// Internal trigger, auto-calibration and diagnostics are started with GO, the external
+    /// trigger modes by the IN/TRIG pin, and PWM/analog, audio-to-vibe and real-time
+    /// playback drive the actuator continuously while the device is out of standby.
+    pub fn trigger_source(&self) -> TriggerSource {
+        match self {
+            OperatingMode::Internal
+            | OperatingMode::AutoCalibration
+            | OperatingMode::Diagnostics => TriggerSource::Go,
+            OperatingMode::ExternalEdge | OperatingMode::ExternalLevel => TriggerSource::Pin,
+            OperatingMode::PwmOrAnalog | OperatingMode::AudioToVibe | OperatingMode::Playback => {
+                TriggerSource::Continuous
+            }
+        }
+    }
+}
+
 /// Device status information
+///
+/// Marked `#[non_exhaustive]` so new status fields can be added without a breaking change;
+/// obtain it from `get_status()`.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[non_exhaustive]
 pub struct StatusInfo {
     /// Overcurrent detection flag
     pub overcurrent_detected: bool,
@@ -110,11 +298,319 @@ pub struct StatusInfo {
     pub illegal_address: bool,
     /// Device identifier (3=DRV2605, 4=DRV2604, 6=DRV2604L, 7=DRV2605L)
     pub device_id: u8,
+    /// Raw status register byte, e.g. for verbatim logging
+    pub raw: u8,
+}
+
+impl From<ll::field_sets::Status> for StatusInfo {
+    fn from(status: ll::field_sets::Status) -> Self {
+        Self {
+            overcurrent_detected: status.oc_detect(),
+            overtemperature_detected: status.over_temp(),
+            feedback_status: status.fb_sts(),
+            diagnostic_result: status.diag_result(),
+            illegal_address: status.illegal_addr(),
+            device_id: status.device_id(),
+            raw: <[u8; 1]>::from(status)[0],
+        }
+    }
+}
+
+impl StatusInfo {
+    /// Get the device variant identified by the status register
+    pub fn variant(&self) -> DeviceVariant {
+        DeviceVariant::from_device_id(self.device_id)
+    }
+
+    /// Get the thermal state
+    pub fn thermal_status(&self) -> ThermalStatus {
+        if self.overtemperature_detected {
+            ThermalStatus::Shutdown
+        } else {
+            ThermalStatus::Normal
+        }
+    }
+
+    /// Get the active fault condition, if any
+    ///
+    /// Overcurrent takes priority if both fault flags are set.
+    pub fn fault(&self) -> Option<Fault> {
+        if self.overcurrent_detected {
+            Some(Fault::OverCurrent)
+        } else if self.overtemperature_detected {
+            Some(Fault::OverTemperature)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "bitflags")]
+bitflags::bitflags! {
+    /// Status register flags as a typed bit set
+    ///
+    /// The device ID bits are not included; use [`StatusInfo::device_id`] for those.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct StatusFlags: u8 {
+        /// Overcurrent detected (OC_DETECT)
+        const OVER_CURRENT = 1 << 0;
+        /// Overtemperature detected (OVER_TEMP)
+        const OVER_TEMP = 1 << 1;
+        /// Feedback status (FB_STS, DRV2604/DRV2605 only)
+        const FB_STS = 1 << 2;
+        /// Diagnostic or calibration result (DIAG_RESULT)
+        const DIAG_RESULT = 1 << 3;
+        /// Illegal register address accessed (ILLEGAL_ADDR, DRV2604/DRV2604L only)
+        const ILLEGAL_ADDR = 1 << 4;
+    }
+}
+
+#[cfg(all(feature = "bitflags", feature = "defmt-03"))]
+impl defmt::Format for StatusFlags {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "StatusFlags({=u8:#x})", self.bits())
+    }
+}
+
+#[cfg(feature = "bitflags")]
+impl StatusInfo {
+    /// Get the status flags as a typed bit set
+    pub fn flags(&self) -> StatusFlags {
+        StatusFlags::from_bits_truncate(self.raw)
+    }
+}
+
+/// Thermal state reported by the status register
+///
+/// The DRV260X has no thermal foldback/derating stage: the only thermal indication is the
+/// latching OVER_TEMP flag, set when the die gets too hot and the device shuts down its
+/// output. The flag clears when the status register is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum ThermalStatus {
+    /// Device temperature within the operating range
+    Normal,
+    /// Overtemperature detected, output was shut down
+    Shutdown,
+}
+
+/// Device fault condition reported by the status register
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum Fault {
+    /// Overcurrent detected on the actuator outputs
+    OverCurrent,
+    /// Device temperature exceeded the overtemperature threshold
+    OverTemperature,
+}
+
+/// DRV260X device variant, decoded from the status register device ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum DeviceVariant {
+    /// DRV2605 (device ID 3)
+    Drv2605,
+    /// DRV2604 (device ID 4)
+    Drv2604,
+    /// DRV2604L (device ID 6)
+    Drv2604L,
+    /// DRV2605L (device ID 7)
+    Drv2605L,
+    /// Unrecognized device ID
+    Unknown,
+}
+
+impl DeviceVariant {
+    /// Decode a device variant from the 3-bit device ID
+    pub fn from_device_id(device_id: u8) -> Self {
+        match device_id {
+            3 => DeviceVariant::Drv2605,
+            4 => DeviceVariant::Drv2604,
+            6 => DeviceVariant::Drv2604L,
+            7 => DeviceVariant::Drv2605L,
+            _ => DeviceVariant::Unknown,
+        }
+    }
+
+    /// Get the model name of the variant (e.g. `"DRV2605L"`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeviceVariant::Drv2605 => "DRV2605",
+            DeviceVariant::Drv2604 => "DRV2604",
+            DeviceVariant::Drv2604L => "DRV2604L",
+            DeviceVariant::Drv2605L => "DRV2605L",
+            DeviceVariant::Unknown => "Unknown",
+        }
+    }
+
+    /// Check whether the variant has the licensed ROM effect library (DRV2605/DRV2605L)
+    pub fn has_rom_library(&self) -> bool {
+        matches!(self, DeviceVariant::Drv2605 | DeviceVariant::Drv2605L)
+    }
+
+    /// Check whether the variant has waveform RAM for uploaded effects (DRV2604/DRV2604L)
+    pub fn has_ram(&self) -> bool {
+        matches!(self, DeviceVariant::Drv2604 | DeviceVariant::Drv2604L)
+    }
+
+    /// Get the variant's reset value of the RATED_VOLTAGE register
+    ///
+    /// 0x3F on the DRV2604/DRV2605 and 0x3E on the DRV2604L/DRV2605L, as listed for register
+    /// 0x16 in each datasheet's register map (and in the `RatedVoltage` entry of
+    /// `device.yaml`); `None` for an unknown variant.
+    pub fn default_rated_voltage(&self) -> Option<u8> {
+        match self {
+            DeviceVariant::Drv2604 | DeviceVariant::Drv2605 => Some(0x3F),
+            DeviceVariant::Drv2604L | DeviceVariant::Drv2605L => Some(0x3E),
+            DeviceVariant::Unknown => None,
+        }
+    }
+
+    /// Get the variant's reset value of the OD_CLAMP register
+    ///
+    /// 0x89 on the DRV2604/DRV2605, 0x9B on the DRV2604L and 0x8C on the DRV2605L, as listed
+    /// for register 0x17 in each datasheet's register map (and in the `OverdriveClampVoltage`
+    /// entry of `device.yaml`); `None` for an unknown variant. Note that the low-voltage
+    /// parts reset to a *higher* clamp; see
+    /// [`conservative_overdrive_clamp_voltage`](Self::conservative_overdrive_clamp_voltage).
+    pub fn default_overdrive_clamp_voltage(&self) -> Option<u8> {
+        match self {
+            DeviceVariant::Drv2604 | DeviceVariant::Drv2605 => Some(0x89),
+            DeviceVariant::Drv2604L => Some(0x9B),
+            DeviceVariant::Drv2605L => Some(0x8C),
+            DeviceVariant::Unknown => None,
+        }
+    }
+
+    /// Get an OD_CLAMP value that does not exceed any variant's reset value
+    ///
+    /// The variant's reset value capped at the standard parts' 0x89. The DRV2604L (0x9B) and
+    /// DRV2605L (0x8C) reset to a higher clamp than the DRV2604/DRV2605 despite their lower
+    /// supply range, so restoring their reset value could over-drive an actuator rated for
+    /// the standard parts. `None` for an unknown variant.
+    pub fn conservative_overdrive_clamp_voltage(&self) -> Option<u8> {
+        self.default_overdrive_clamp_voltage()
+            .map(|clamp| clamp.min(0x89))
+    }
+}
+
+/// Feedback control register settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct FeedbackControl {
+    /// Feedback loop gain
+    pub loop_gain: LoopGain,
+    /// Feedback brake factor
+    pub brake_factor: FbBrakeFactor,
+    /// Back-EMF gain
+    pub bemf_gain: BemfGain,
+    /// Actuator type selected by the N_ERM_LRA bit
+    pub actuator: Actuator,
+}
+
+/// Auto-calibration results returned by `run_auto_calibration()`
+///
+/// The device writes these values into A_CAL_COMP, A_CAL_BEMF and BEMF_GAIN when
+/// calibration succeeds; store them to restore the calibration on later power-ups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct CalibrationResult {
+    /// Auto-calibration compensation result (A_CAL_COMP)
+    pub compensation: u8,
+    /// Auto-calibration back-EMF result (A_CAL_BEMF)
+    pub back_emf: u8,
+    /// Back-EMF gain selected by calibration (BEMF_GAIN)
+    pub bemf_gain: BemfGain,
+}
+
+/// Control3 options applied together by `set_control3_fields()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Control3Fields {
+    /// ERM open-loop operation (ERM_OPEN_LOOP)
+    pub erm_open_loop: bool,
+    /// LRA open-loop operation (LRA_OPEN_LOOP)
+    pub lra_open_loop: bool,
+    /// Signed RTP data format (inverse of DATA_FORMAT_RTP)
+    pub rtp_signed: bool,
+    /// Analog rather than PWM input on IN/TRIG (N_PWM_ANALOG)
+    pub analog_input: bool,
+    /// Supply compensation enabled (inverse of SUPPLY_COMP_DIS)
+    pub supply_compensation: bool,
+}
+
+/// Snapshot of the Control1-Control4 registers returned by `get_control_config()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct ControlConfig {
+    /// Raw drive time (DRIVE_TIME); 0.5 ms + 0.1 ms/LSB for LRA, 1 ms + 0.2 ms/LSB for ERM
+    pub drive_time: u8,
+    /// AC coupling of the analog input (AC_COUPLE)
+    pub ac_couple: bool,
+    /// Startup boost enabled (STARTUP_BOOST)
+    pub startup_boost: bool,
+    /// Raw current dissipation time (IDISS_TIME)
+    pub idiss_time: u8,
+    /// Raw blanking time (BLANKING_TIME)
+    pub blanking_time: u8,
+    /// LRA auto-resonance sample time (SAMPLE_TIME)
+    pub sample_time: SampleTime,
+    /// Brake stabilizer enabled (BRAKE_STABILIZER)
+    pub brake_stabilizer: bool,
+    /// Bidirectional input mode (BIDIR_INPUT)
+    pub bidirectional_input: bool,
+    /// LRA open-loop operation (LRA_OPEN_LOOP)
+    pub lra_open_loop: bool,
+    /// Analog rather than PWM input on IN/TRIG (N_PWM_ANALOG)
+    pub analog_input: bool,
+    /// LRA amplitude updated twice per resonance cycle (LRA_DRIVE_MODE)
+    pub lra_drive_twice_per_cycle: bool,
+    /// Signed RTP data format (inverse of DATA_FORMAT_RTP)
+    pub rtp_signed: bool,
+    /// Supply compensation enabled (inverse of SUPPLY_COMP_DIS)
+    pub supply_compensation: bool,
+    /// ERM open-loop operation (ERM_OPEN_LOOP)
+    pub erm_open_loop: bool,
+    /// PWM/analog noise gate threshold (NG_THRESH)
+    pub noise_gate: NoiseGateThreshold,
+    /// OTP memory has been programmed (OTP_STATUS)
+    pub otp_programmed: bool,
+    /// Auto-calibration time (AUTO_CAL_TIME)
+    pub auto_cal_time: AutoCalibTime,
+    /// Zero-crossing detection time (ZC_DET_TIME, DRV2604L/DRV2605L only)
+    pub zero_cross_time: ZeroCrossTime,
+}
+
+/// Result of a `self_test()` run, one flag per step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct SelfTestReport {
+    /// The device ID matched the enabled device feature
+    pub device_id_ok: bool,
+    /// Diagnostics completed without reporting an actuator fault
+    pub diagnostics_passed: bool,
+    /// Auto-calibration completed successfully, or `None` if it was not run
+    pub calibration_passed: Option<bool>,
+    /// The test effect played to completion without a fault
+    pub effect_played: bool,
+}
+
+impl SelfTestReport {
+    /// Check whether every step that was run passed
+    pub fn passed(&self) -> bool {
+        self.device_id_ok
+            && self.diagnostics_passed
+            && self.calibration_passed.unwrap_or(true)
+            && self.effect_played
+    }
 }
 
 /// All possible errors in this crate
+///
+/// Marked `#[non_exhaustive]` so new error conditions can be added without a breaking change.
 #[derive(Debug)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[non_exhaustive]
 pub enum Error<E> {
     /// I2C communication error
     I2c(E),
@@ -133,6 +629,37 @@ pub enum Error<E> {
     Timeout,
     /// Invalid waveform sequence
     InvalidWaveform,
+    /// Operation not supported by the detected device variant (e.g. ROM library on DRV2604)
+    UnsupportedOnVariant,
+    /// Auto-calibration completed but reported failure (DIAG_RESULT set or a fault latched)
+    CalibrationFailed(StatusInfo),
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::I2c(e) => write!(f, "I2C error: {e}"),
+            Error::InvalidDeviceId { expected, found } => {
+                write!(f, "invalid device id: expected {expected}, found {found}")
+            }
+            Error::NotReady => write!(f, "device not ready"),
+            Error::InvalidConfig(msg) => write!(f, "invalid configuration: {msg}"),
+            Error::Timeout => write!(f, "operation timeout"),
+            Error::InvalidWaveform => write!(f, "invalid waveform sequence"),
+            Error::UnsupportedOnVariant => {
+                write!(f, "operation not supported on this device variant")
+            }
+            Error::CalibrationFailed(status) => {
+                write!(f, "auto-calibration failed (status {:#04x})", status.raw)
+            }
+        }
+    }
+}
+
+impl<E> From<effects::InvalidWaveform> for Error<E> {
+    fn from(_: effects::InvalidWaveform) -> Self {
+        Error::InvalidWaveform
+    }
 }
 
 // Implement From conversion for ll::DeviceInterfaceError
@@ -140,32 +667,211 @@ impl<E> From<ll::DeviceInterfaceError<E>> for Error<E> {
     fn from(error: ll::DeviceInterfaceError<E>) -> Self {
         match error {
             ll::DeviceInterfaceError::I2c(e) => Error::I2c(e),
+            ll::DeviceInterfaceError::BufferOverflow => {
+                Error::InvalidConfig("register write exceeds interface buffer")
+            }
         }
     }
 }
 
 /// High-level DRV260X driver
+///
+/// Blocking and async methods are selected by the traits `I2C` implements, so a bus type
+/// implementing both can use either API on the same instance (see the crate-level docs).
+///
+/// Setters for individual fields perform a read-modify-write of the whole register, so
+/// changing several fields of one register costs one read and one write each, and the
+/// register may change on the device between the read and the write. Where this matters,
+/// use the combined setters (e.g. `set_control3_fields()`) that update all fields in a
+/// single cycle.
 pub struct Drv260x<I2C> {
     device: ll::Registers<ll::DeviceInterface<I2C>>,
     // Device state tracking
     current_mode: Option<OperatingMode>,
+    variant: Option<DeviceVariant>,
+    standby_mode: Option<OperatingMode>,
+    // Set while an async playback future is in flight; left set if it was dropped
+    stop_pending: bool,
 }
 
 impl<I2C> Drv260x<I2C> {
     /// Create a new DRV260X driver instance
     pub fn new(i2c: I2C) -> Self {
+        Self::with_address(i2c, I2C_ADDRESS)
+    }
+
+    /// Create a new driver instance talking to a non-default I2C address
+    pub(crate) fn with_address(i2c: I2C, address: u8) -> Self {
         Self {
-            device: ll::Registers::new(ll::DeviceInterface { i2c }),
+            device: ll::Registers::new(ll::DeviceInterface {
+                i2c,
+                address,
+                retries: 0,
+            }),
             current_mode: None,
+            variant: None,
+            standby_mode: None,
+            stop_pending: false,
         }
     }
 
+    /// Create a driver on a bus shared with other devices
+    ///
+    /// Equivalent to [`new`](Self::new); it exists to make shared-bus setups explicit. Any
+    /// [`I2c`](embedded_hal::i2c::I2c) implementation works, including the borrowing bus
+    /// devices from `embedded-hal-bus`, since the driver has no `'static` bounds:
+    ///
+    /// ```rust,ignore
+    /// use core::cell::RefCell;
+    /// use embedded_hal_bus::i2c::RefCellDevice;
+    ///
+    /// let bus = RefCell::new(i2c);
+    /// let mut haptic = Drv260x::on_shared_bus(RefCellDevice::new(&bus));
+    /// let mut sensor = Sensor::new(RefCellDevice::new(&bus));
+    /// ```
+    pub fn on_shared_bus(device: I2C) -> Self {
+        Self::new(device)
+    }
+
+    /// Create a driver that retries register accesses failing with an I2C error
+    ///
+    /// Each register read or write is attempted up to `retries + 1` times before
+    /// `Error::I2c` is returned, which hides occasional NAKs on a noisy bus. Writes with side
+    /// effects (GO, DEV_RESET, OTP_PROGRAM) are never retried; see
+    /// [`DeviceInterface::retries`](ll::DeviceInterface::retries). See also
+    /// [`set_retries`](Self::set_retries).
+    pub fn with_retries(i2c: I2C, retries: u8) -> Self {
+        let mut driver = Self::new(i2c);
+        driver.set_retries(retries);
+        driver
+    }
+
+    /// Set the number of extra attempts for register accesses failing with an I2C error
+    ///
+    /// 0 (the default) disables retries. See
+    /// [`DeviceInterface::retries`](ll::DeviceInterface::retries) for the writes that are
+    /// never retried.
+    pub fn set_retries(&mut self, retries: u8) {
+        self.device.interface().retries = retries;
+    }
+
+    /// Create a builder for construction-time configuration
+    pub fn builder(i2c: I2C) -> Drv260xBuilder<I2C> {
+        Drv260xBuilder::new(i2c)
+    }
+
+    /// Temporarily borrow the I2C bus, e.g. to scan the bus or talk to another device
+    ///
+    /// The driver's cached state is not touched, so `f` must not change this device's
+    /// registers behind the driver's back.
+    pub fn with_i2c<R>(&mut self, f: impl FnOnce(&mut I2C) -> R) -> R {
+        f(&mut self.device.interface().i2c)
+    }
+
     /// Get a reference to the underlying device for advanced operations
     pub fn device(&mut self) -> &mut ll::Registers<ll::DeviceInterface<I2C>> {
         &mut self.device
     }
+
+    /// Get the device variant detected during `init`, or `None` if not yet initialized
+    pub fn variant(&self) -> Option<DeviceVariant> {
+        self.variant
+    }
+
+    /// Check whether an async playback future was dropped before it could stop playback
+    ///
+    /// Futures cannot await bus transactions when dropped, so a cancelled
+    /// `stream_rtp_async` leaves the last RTP sample playing, as does a `PlaybackGuard`
+    /// whose stop on drop did not complete. Call `clear_pending_stop_async()` to stop it.
+    pub fn is_stop_pending(&self) -> bool {
+        self.stop_pending
+    }
+
+    /// Reject ROM library and audio-to-vibe operations when the variant detected during
+    /// `init` has no ROM library
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    pub(crate) fn ensure_rom_library<E>(&self) -> Result<(), Error<E>> {
+        match self.variant {
+            Some(DeviceVariant::Drv2604 | DeviceVariant::Drv2604L) => {
+                Err(Error::UnsupportedOnVariant)
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 // The sync and async implementations are now in separate modules and are
 // automatically included via the module system. This makes lib.rs much cleaner
 // and more maintainable.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_feedback_actuator_rejects_mismatched_actuator() {
+        assert!(check_feedback_actuator::<()>(false, Actuator::Erm).is_ok());
+        assert!(check_feedback_actuator::<()>(true, Actuator::Lra).is_ok());
+        assert!(matches!(
+            check_feedback_actuator::<()>(false, Actuator::Lra),
+            Err(Error::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            check_feedback_actuator::<()>(true, Actuator::Erm),
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn playback_timeout_allows_double_duration_plus_margin() {
+        assert_eq!(playback_timeout_us(0), 500_000);
+        assert_eq!(playback_timeout_us(100), 700_000);
+        assert_eq!(playback_timeout_us(u16::MAX), 131_570_000);
+    }
+
+    /// Every variant with its device ID (if any), ROM library and RAM support
+    const VARIANT_CAPABILITIES: [(DeviceVariant, Option<u8>, bool, bool); 5] = [
+        (DeviceVariant::Drv2605, Some(3), true, false),
+        (DeviceVariant::Drv2604, Some(4), false, true),
+        (DeviceVariant::Drv2604L, Some(6), false, true),
+        (DeviceVariant::Drv2605L, Some(7), true, false),
+        (DeviceVariant::Unknown, None, false, false),
+    ];
+
+    #[test]
+    fn variant_capabilities_match_table() {
+        for (variant, _, rom, ram) in VARIANT_CAPABILITIES {
+            assert_eq!(variant.has_rom_library(), rom, "{variant:?} ROM library");
+            assert_eq!(variant.has_ram(), ram, "{variant:?} RAM");
+        }
+    }
+
+    #[test]
+    fn conservative_clamp_never_exceeds_any_reset_value() {
+        let lowest_reset = VARIANT_CAPABILITIES
+            .iter()
+            .filter_map(|(variant, _, _, _)| variant.default_overdrive_clamp_voltage())
+            .min();
+        for (variant, _, _, _) in VARIANT_CAPABILITIES {
+            let clamp = variant.conservative_overdrive_clamp_voltage();
+            assert_eq!(clamp.is_some(), variant != DeviceVariant::Unknown);
+            assert!(clamp <= lowest_reset, "{variant:?} OD_CLAMP");
+        }
+    }
+
+    #[test]
+    fn every_device_id_decodes_to_a_listed_variant() {
+        for id in 0..8 {
+            let expected = VARIANT_CAPABILITIES
+                .iter()
+                .find(|(_, variant_id, _, _)| *variant_id == Some(id))
+                .map_or(DeviceVariant::Unknown, |(variant, _, _, _)| *variant);
+            assert_eq!(
+                DeviceVariant::from_device_id(id),
+                expected,
+                "device ID {id}"
+            );
+        }
+    }
+}
@@ -5,12 +5,57 @@ use embedded_hal::i2c::I2c;
 /// I2C address of the DRV260X family
 pub const I2C_ADDRESS: u8 = 0x5A;
 
+/// Time after DEV_RESET before the device accepts I2C transactions again
+///
+/// DEV_RESET self-clears once the reset has completed; poll it after this delay.
+pub const RESET_SETTLE_US: u32 = 1_000;
+
+/// Time to wait after clearing STANDBY before triggering playback
+///
+/// The datasheet gives no separate figure for leaving software standby, so this uses the
+/// 250 µs EN wake-up time, which covers the longer power-up path. The EN pin wrapper waits
+/// the same time (see `enable::EN_SETTLE_TIME_US`).
+pub const STANDBY_EXIT_US: u32 = 250;
+
+/// Longest auto-calibration run, at the maximum AUTO_CAL_TIME setting (1000-1200 ms)
+pub const CALIBRATION_MAX_US: u32 = 1_200_000;
+
+/// Maximum number of data bytes in a single register write (the full waveform sequencer)
+const MAX_WRITE_LEN: usize = 8;
+
+/// MODE register address and its DEV_RESET bit
+const MODE_ADDRESS: u8 = 0x01;
+const DEV_RESET_BIT: u8 = 0x80;
+
+/// GO register address
+const GO_ADDRESS: u8 = 0x0C;
+
+/// Control4 register address and its OTP_PROGRAM bit
+const CONTROL4_ADDRESS: u8 = 0x1E;
+const OTP_PROGRAM_BIT: u8 = 0x01;
+
+/// Check whether a failed write may be repeated
+///
+/// Writes to GO, and MODE or Control4 writes that set DEV_RESET or OTP_PROGRAM, act on the
+/// device as they are written. If such a write reached the device and was NAKed afterwards,
+/// repeating it would trigger playback, reset or OTP programming a second time.
+fn write_is_retry_safe(address: u8, data: &[u8]) -> bool {
+    match (address, data.first()) {
+        (GO_ADDRESS, _) => false,
+        (MODE_ADDRESS, Some(value)) => value & DEV_RESET_BIT == 0,
+        (CONTROL4_ADDRESS, Some(value)) => value & OTP_PROGRAM_BIT == 0,
+        _ => true,
+    }
+}
+
 /// Device interface error types
 #[derive(Debug)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 pub enum DeviceInterfaceError<I2cError> {
     /// I2C communication error
     I2c(I2cError),
+    /// Write payload exceeds the interface's transfer buffer
+    BufferOverflow,
 }
 
 #[allow(missing_docs)]
@@ -27,6 +72,14 @@ pub use device_generated::*;
 pub struct DeviceInterface<I2c> {
     /// The I2C interface
     pub i2c: I2c,
+    /// 7-bit I2C device address (normally [`I2C_ADDRESS`])
+    pub address: u8,
+    /// Extra attempts for a register access that fails with an I2C error (0 = no retries)
+    ///
+    /// Writes with side effects are never retried: any write to GO, and MODE or Control4
+    /// writes that set DEV_RESET or OTP_PROGRAM, fail on the first I2C error, since a write
+    /// that reached the device but was NAKed afterwards would otherwise be applied twice.
+    pub retries: u8,
 }
 
 impl<I2cTrait: I2c> device_driver::RegisterInterface for DeviceInterface<I2cTrait> {
@@ -39,9 +92,14 @@ impl<I2cTrait: I2c> device_driver::RegisterInterface for DeviceInterface<I2cTrai
         _size_bits: u32,
         data: &mut [u8],
     ) -> Result<(), Self::Error> {
-        self.i2c
-            .write_read(I2C_ADDRESS, &[address], data)
-            .map_err(DeviceInterfaceError::I2c)
+        let mut retries = self.retries;
+        loop {
+            match self.i2c.write_read(self.address, &[address], data) {
+                Ok(()) => return Ok(()),
+                Err(_) if retries > 0 => retries -= 1,
+                Err(e) => return Err(DeviceInterfaceError::I2c(e)),
+            }
+        }
     }
 
     fn write_register(
@@ -50,12 +108,25 @@ impl<I2cTrait: I2c> device_driver::RegisterInterface for DeviceInterface<I2cTrai
         _size_bits: u32,
         data: &[u8],
     ) -> Result<(), Self::Error> {
-        let mut buf = [0u8; 9]; // Max for multi-byte writes (address + up to 8 bytes)
+        if data.len() > MAX_WRITE_LEN {
+            return Err(DeviceInterfaceError::BufferOverflow);
+        }
+
+        let mut buf = [0u8; MAX_WRITE_LEN + 1]; // Address + up to 8 data bytes
         buf[0] = address;
         buf[1..1 + data.len()].copy_from_slice(data);
-        self.i2c
-            .write(I2C_ADDRESS, &buf[..1 + data.len()])
-            .map_err(DeviceInterfaceError::I2c)
+        let mut retries = if write_is_retry_safe(address, data) {
+            self.retries
+        } else {
+            0
+        };
+        loop {
+            match self.i2c.write(self.address, &buf[..1 + data.len()]) {
+                Ok(()) => return Ok(()),
+                Err(_) if retries > 0 => retries -= 1,
+                Err(e) => return Err(DeviceInterfaceError::I2c(e)),
+            }
+        }
     }
 }
 
@@ -72,10 +143,14 @@ impl<I2cTrait: embedded_hal_async::i2c::I2c> device_driver::AsyncRegisterInterfa
         _size_bits: u32,
         data: &mut [u8],
     ) -> Result<(), Self::Error> {
-        self.i2c
-            .write_read(I2C_ADDRESS, &[address], data)
-            .await
-            .map_err(DeviceInterfaceError::I2c)
+        let mut retries = self.retries;
+        loop {
+            match self.i2c.write_read(self.address, &[address], data).await {
+                Ok(()) => return Ok(()),
+                Err(_) if retries > 0 => retries -= 1,
+                Err(e) => return Err(DeviceInterfaceError::I2c(e)),
+            }
+        }
     }
 
     async fn write_register(
@@ -84,12 +159,108 @@ impl<I2cTrait: embedded_hal_async::i2c::I2c> device_driver::AsyncRegisterInterfa
         _size_bits: u32,
         data: &[u8],
     ) -> Result<(), Self::Error> {
-        let mut buf = [0u8; 9]; // Max for multi-byte writes (address + up to 8 bytes)
+        if data.len() > MAX_WRITE_LEN {
+            return Err(DeviceInterfaceError::BufferOverflow);
+        }
+
+        let mut buf = [0u8; MAX_WRITE_LEN + 1]; // Address + up to 8 data bytes
         buf[0] = address;
         buf[1..1 + data.len()].copy_from_slice(data);
-        self.i2c
-            .write(I2C_ADDRESS, &buf[..1 + data.len()])
-            .await
-            .map_err(DeviceInterfaceError::I2c)
+        let mut retries = if write_is_retry_safe(address, data) {
+            self.retries
+        } else {
+            0
+        };
+        loop {
+            match self.i2c.write(self.address, &buf[..1 + data.len()]).await {
+                Ok(()) => return Ok(()),
+                Err(_) if retries > 0 => retries -= 1,
+                Err(e) => return Err(DeviceInterfaceError::I2c(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use device_driver::RegisterInterface;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+    #[test]
+    fn write_register_rejects_oversized_payload_without_bus_traffic() {
+        let mut i2c = I2cMock::new(&[]);
+        let mut interface = DeviceInterface {
+            i2c: i2c.clone(),
+            address: I2C_ADDRESS,
+            retries: 0,
+        };
+
+        let result = interface.write_register(0x04, 72, &[0; MAX_WRITE_LEN + 1]);
+        assert!(matches!(result, Err(DeviceInterfaceError::BufferOverflow)));
+
+        i2c.done();
+    }
+
+    #[test]
+    fn write_register_accepts_full_buffer() {
+        let mut expected = vec![0x04];
+        expected.extend_from_slice(&[0xAA; MAX_WRITE_LEN]);
+        let mut i2c = I2cMock::new(&[I2cTransaction::write(I2C_ADDRESS, expected)]);
+        let mut interface = DeviceInterface {
+            i2c: i2c.clone(),
+            address: I2C_ADDRESS,
+            retries: 0,
+        };
+
+        interface
+            .write_register(0x04, 64, &[0xAA; MAX_WRITE_LEN])
+            .unwrap();
+
+        i2c.done();
+    }
+
+    #[test]
+    fn write_register_retries_plain_writes() {
+        let failing = I2cTransaction::write(I2C_ADDRESS, vec![0x16, 0x3E])
+            .with_error(embedded_hal::i2c::ErrorKind::Other);
+        let mut i2c = I2cMock::new(&[
+            failing,
+            I2cTransaction::write(I2C_ADDRESS, vec![0x16, 0x3E]),
+        ]);
+        let mut interface = DeviceInterface {
+            i2c: i2c.clone(),
+            address: I2C_ADDRESS,
+            retries: 2,
+        };
+
+        interface.write_register(0x16, 8, &[0x3E]).unwrap();
+
+        i2c.done();
+    }
+
+    #[test]
+    fn write_register_does_not_retry_nacked_go_write() {
+        let mut i2c = I2cMock::new(&[I2cTransaction::write(I2C_ADDRESS, vec![0x0C, 0x01])
+            .with_error(embedded_hal::i2c::ErrorKind::Other)]);
+        let mut interface = DeviceInterface {
+            i2c: i2c.clone(),
+            address: I2C_ADDRESS,
+            retries: 2,
+        };
+
+        let result = interface.write_register(0x0C, 8, &[0x01]);
+        assert!(matches!(result, Err(DeviceInterfaceError::I2c(_))));
+
+        i2c.done();
+    }
+
+    #[test]
+    fn side_effect_writes_are_not_retry_safe() {
+        assert!(!write_is_retry_safe(0x01, &[0x80]));
+        assert!(write_is_retry_safe(0x01, &[0x40]));
+        assert!(!write_is_retry_safe(0x1E, &[0x21]));
+        assert!(write_is_retry_safe(0x1E, &[0x20]));
+        assert!(write_is_retry_safe(0x04, &[0x01; MAX_WRITE_LEN]));
     }
 }
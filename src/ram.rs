@@ -0,0 +1,418 @@
+//! Waveform RAM library layout (DRV2604/DRV2604L only)
+//!
+//! The DRV2604 and DRV2604L have no ROM library; effects are played from a 2 kB waveform
+//! RAM instead. The layout follows the "Waveform RAM" header format of the DRV2604
+//! datasheet (SLOS825): the RAM starts with a header describing where each waveform lives,
+//! followed by the waveform data:
+//!
+//! - Address 0: header size byte, the number of header entry bytes minus one (`3 × N - 1`
+//!   for N waveforms), so the waveform data starts at address `3 × N + 1`
+//! - One 3-byte entry per waveform: start address (upper, lower; bit 7 of the upper byte
+//!   selects voltage-time playback) and a size/repeat byte (repeats in bits 7-5, data size
+//!   in bits 4-0)
+//! - Waveform data as voltage-time pairs: amplitude (bit 7 selects a linear ramp to the
+//!   next pair) and duration in 5 ms steps
+//!
+//! [`RamLibraryBuilder`] assembles this layout into a caller-provided buffer, and the
+//! resulting bytes are uploaded with `write_ram_waveforms()`. [`decode_header`] and
+//! [`decode_waveform`] read an image back, e.g. to inspect a library built elsewhere:
+//!
+//! ```rust,ignore
+//! use drv260x::ram::{RamLibraryBuilder, RamSample};
+//!
+//! let mut buf = [0u8; 64];
+//! let mut builder = RamLibraryBuilder::new(&mut buf, 2)?;
+//! let click = builder.add_waveform(&[RamSample::new(0x7F, 4)], 0)?;
+//! let swell = builder.add_waveform(&[RamSample::ramp(0x10, 20), RamSample::new(0x7F, 10)], 1)?;
+//! haptic.write_ram_waveforms(builder.finish()?)?;
+//! haptic.set_single_effect(click)?;
+//! ```
+
+use crate::Error;
+
+/// Size of the waveform RAM in bytes
+pub const RAM_SIZE: usize = 2048;
+
+/// Repeat count that plays a waveform until stopped
+pub const REPEAT_FOREVER: u8 = 7;
+
+/// Size of one waveform entry in the header
+const HEADER_ENTRY_LEN: usize = 3;
+
+/// Most waveforms whose header size (`3 × N - 1`) fits in the header size byte
+pub const MAX_WAVEFORMS: u8 = 85;
+
+/// Largest waveform data size expressible in the 5-bit size field
+const MAX_WAVEFORM_LEN: usize = 0x1F;
+
+/// Start address flag selecting voltage-time pair playback
+const VOLTAGE_TIME_FLAG: u8 = 0x80;
+
+/// Amplitude flag requesting a linear ramp to the next pair
+const RAMP_FLAG: u8 = 0x80;
+
+/// One voltage-time pair of a RAM waveform
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RamSample {
+    /// Drive amplitude (0-127)
+    pub amplitude: u8,
+    /// Duration in 5 ms steps
+    pub duration: u8,
+    /// Ramp linearly from this amplitude to the next pair instead of holding it
+    pub ramp: bool,
+}
+
+impl RamSample {
+    /// Hold `amplitude` for `duration` × 5 ms
+    pub const fn new(amplitude: u8, duration: u8) -> Self {
+        Self {
+            amplitude,
+            duration,
+            ramp: false,
+        }
+    }
+
+    /// Ramp from `amplitude` to the next pair over `duration` × 5 ms
+    pub const fn ramp(amplitude: u8, duration: u8) -> Self {
+        Self {
+            amplitude,
+            duration,
+            ramp: true,
+        }
+    }
+}
+
+/// Builder for a waveform RAM image in the DRV2604 header layout
+///
+/// The number of waveforms is fixed up front so the header can be reserved at the start
+/// of the buffer; waveform data is appended behind it as waveforms are added. The buffer
+/// may be smaller than [`RAM_SIZE`] if the library is known to be small.
+pub struct RamLibraryBuilder<'a> {
+    buf: &'a mut [u8],
+    waveforms: u8,
+    added: u8,
+    data_end: usize,
+}
+
+impl<'a> RamLibraryBuilder<'a> {
+    /// Start a library of `waveforms` waveforms in `buf`
+    ///
+    /// Returns `Error::InvalidConfig` if `waveforms` is zero or above [`MAX_WAVEFORMS`], or
+    /// the header does not fit.
+    pub fn new<E>(buf: &'a mut [u8], waveforms: u8) -> Result<Self, Error<E>> {
+        let header_len = 1 + HEADER_ENTRY_LEN * usize::from(waveforms);
+        if waveforms == 0 || waveforms > MAX_WAVEFORMS {
+            return Err(Error::InvalidConfig("RAM library needs 1 to 85 waveforms"));
+        }
+        if header_len > buf.len().min(RAM_SIZE) {
+            return Err(Error::InvalidConfig("RAM library header does not fit"));
+        }
+
+        buf[0] = (header_len - 2) as u8;
+        Ok(Self {
+            buf,
+            waveforms,
+            added: 0,
+            data_end: header_len,
+        })
+    }
+
+    /// Append a waveform and return its effect index
+    ///
+    /// The returned index (starting at 1) is what the waveform sequencer and
+    /// `set_single_effect()` use to play the waveform. `repeats` is the number of extra
+    /// playbacks (0-6), or [`REPEAT_FOREVER`].
+    ///
+    /// Returns `Error::InvalidWaveform` if `samples` is empty, longer than 15 pairs or has
+    /// an amplitude above 127, and `Error::InvalidConfig` if `repeats` is out of range, all
+    /// declared waveforms were already added or the data does not fit.
+    pub fn add_waveform<E>(&mut self, samples: &[RamSample], repeats: u8) -> Result<u8, Error<E>> {
+        let len = samples.len() * 2;
+        if samples.is_empty()
+            || len > MAX_WAVEFORM_LEN
+            || samples.iter().any(|sample| sample.amplitude > 0x7F)
+        {
+            return Err(Error::InvalidWaveform);
+        }
+        if repeats > REPEAT_FOREVER {
+            return Err(Error::InvalidConfig(
+                "RAM waveform repeat count exceeds 3 bits",
+            ));
+        }
+        if self.added == self.waveforms {
+            return Err(Error::InvalidConfig(
+                "all declared RAM waveforms already added",
+            ));
+        }
+
+        let start = self.data_end;
+        let end = start + len;
+        if end > self.buf.len().min(RAM_SIZE) {
+            return Err(Error::InvalidConfig("RAM library data does not fit"));
+        }
+
+        for (pair, sample) in self.buf[start..end].chunks_exact_mut(2).zip(samples) {
+            let ramp = if sample.ramp { RAMP_FLAG } else { 0 };
+            pair[0] = ramp | sample.amplitude;
+            pair[1] = sample.duration;
+        }
+
+        let entry = 1 + HEADER_ENTRY_LEN * usize::from(self.added);
+        self.buf[entry] = VOLTAGE_TIME_FLAG | (start >> 8) as u8;
+        self.buf[entry + 1] = start as u8;
+        self.buf[entry + 2] = (repeats << 5) | len as u8;
+
+        self.data_end = end;
+        self.added += 1;
+        Ok(self.added)
+    }
+
+    /// Finish the library and return the RAM image, starting at RAM address 0
+    ///
+    /// Returns `Error::InvalidConfig` if fewer waveforms were added than declared, since
+    /// the unused header entries would point at address 0.
+    pub fn finish<E>(self) -> Result<&'a [u8], Error<E>> {
+        if self.added != self.waveforms {
+            return Err(Error::InvalidConfig(
+                "fewer RAM waveforms added than declared",
+            ));
+        }
+
+        Ok(&self.buf[..self.data_end])
+    }
+}
+
+/// One waveform entry of a RAM header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct RamHeaderEntry {
+    /// RAM address of the first data byte
+    pub start: u16,
+    /// Data size in bytes
+    pub len: u8,
+    /// Extra playbacks, or [`REPEAT_FOREVER`]
+    pub repeats: u8,
+    /// Data is voltage-time pairs rather than raw amplitudes
+    pub voltage_time: bool,
+}
+
+/// Decode the header of a RAM image
+///
+/// Returns the waveform entries in effect index order (the first entry is effect 1), or
+/// `None` if the header size byte is not `3 × N - 1` or the header runs past the image.
+pub fn decode_header(image: &[u8]) -> Option<impl Iterator<Item = RamHeaderEntry> + '_> {
+    let header_len = usize::from(*image.first()?) + 2;
+    if (header_len - 1) % HEADER_ENTRY_LEN != 0 || header_len > image.len() {
+        return None;
+    }
+
+    Some(
+        image[1..header_len]
+            .chunks_exact(HEADER_ENTRY_LEN)
+            .map(|entry| RamHeaderEntry {
+                start: u16::from_be_bytes([entry[0] & !VOLTAGE_TIME_FLAG, entry[1]]),
+                len: entry[2] & MAX_WAVEFORM_LEN as u8,
+                repeats: entry[2] >> 5,
+                voltage_time: entry[0] & VOLTAGE_TIME_FLAG != 0,
+            }),
+    )
+}
+
+/// Decode the voltage-time pairs of one waveform in a RAM image
+///
+/// Returns `None` if the waveform data runs past the image. A trailing odd byte is ignored.
+pub fn decode_waveform(
+    image: &[u8],
+    entry: &RamHeaderEntry,
+) -> Option<impl Iterator<Item = RamSample> + '_> {
+    let start = usize::from(entry.start);
+    let data = image.get(start..start + usize::from(entry.len))?;
+
+    Some(data.chunks_exact(2).map(|pair| RamSample {
+        amplitude: pair[0] & !RAMP_FLAG,
+        duration: pair[1],
+        ramp: pair[0] & RAMP_FLAG != 0,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Error<()>>;
+
+    #[test]
+    fn builds_known_good_image() -> Result<()> {
+        let mut buf = [0xAA; 32];
+        let mut builder = RamLibraryBuilder::new::<()>(&mut buf, 2)?;
+        assert_eq!(
+            builder.add_waveform::<()>(&[RamSample::new(0x7F, 4)], 0)?,
+            1
+        );
+        assert_eq!(
+            builder
+                .add_waveform::<()>(&[RamSample::ramp(0x10, 20), RamSample::new(0x7F, 10)], 1)?,
+            2
+        );
+
+        let expected = [
+            // Header size: two 3-byte entries, minus one (0x05)
+            HEADER_ENTRY_LEN as u8 * 2 - 1,
+            // Waveform 1: starts at 0x0007, 2 bytes, no repeats
+            0x80,
+            0x07,
+            0x02,
+            // Waveform 2: starts at 0x0009, 4 bytes, one repeat
+            0x80,
+            0x09,
+            0x24,
+            // Waveform 1 data
+            0x7F,
+            0x04,
+            // Waveform 2 data, first pair ramping
+            0x90,
+            0x14,
+            0x7F,
+            0x0A,
+        ];
+        assert_eq!(builder.finish::<()>()?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn start_address_upper_byte_keeps_voltage_time_flag() -> Result<()> {
+        let mut buf = [0; 512];
+        // 85 entries fill the header up to address 0x0100
+        let mut builder = RamLibraryBuilder::new::<()>(&mut buf, 85)?;
+        builder.add_waveform::<()>(&[RamSample::new(0x40, 1)], REPEAT_FOREVER)?;
+        drop(builder);
+
+        assert_eq!(&buf[1..4], &[VOLTAGE_TIME_FLAG | 0x01, 0x00, 0xE2]);
+        assert_eq!(&buf[0x100..0x102], &[0x40, 0x01]);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_waveform_longer_than_size_field() -> Result<()> {
+        let mut buf = [0; 64];
+        let mut builder = RamLibraryBuilder::new::<()>(&mut buf, 2)?;
+        let samples = [RamSample::new(0x10, 1); 16];
+
+        builder.add_waveform::<()>(&samples[..MAX_WAVEFORM_LEN / 2], 0)?;
+        assert!(matches!(
+            builder.add_waveform::<()>(&samples, 0),
+            Err(Error::InvalidWaveform)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_data_beyond_ram_size() -> Result<()> {
+        let mut buf = [0; 2 * RAM_SIZE];
+        let mut builder = RamLibraryBuilder::new::<()>(&mut buf, MAX_WAVEFORMS)?;
+        let samples = [RamSample::new(0x10, 1); 15];
+
+        // Header ends at 256; 59 waveforms of 30 bytes end at 2026
+        for _ in 0..59 {
+            builder.add_waveform::<()>(&samples, 0)?;
+        }
+        assert!(matches!(
+            builder.add_waveform::<()>(&samples, 0),
+            Err(Error::InvalidConfig(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_header_larger_than_buffer() {
+        let mut buf = [0; 8];
+        assert!(matches!(
+            RamLibraryBuilder::new::<()>(&mut buf, 3),
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_header_size_beyond_one_byte() {
+        let mut buf = [0; RAM_SIZE];
+        assert!(matches!(
+            RamLibraryBuilder::new::<()>(&mut buf, MAX_WAVEFORMS + 1),
+            Err(Error::InvalidConfig(_))
+        ));
+
+        let builder = RamLibraryBuilder::new::<()>(&mut buf, MAX_WAVEFORMS);
+        drop(builder);
+        assert_eq!(buf[0], 0xFE);
+    }
+
+    #[test]
+    fn decoded_image_round_trips_to_builder_input() -> Result<()> {
+        let waveforms: [(&[RamSample], u8); 3] = [
+            (&[RamSample::new(0x7F, 4)], 0),
+            (&[RamSample::ramp(0x10, 20), RamSample::new(0x7F, 10)], 1),
+            (&[RamSample::new(0x40, 255); 15], REPEAT_FOREVER),
+        ];
+        let mut buf = [0; 64];
+        let mut builder = RamLibraryBuilder::new::<()>(&mut buf, 3)?;
+        for (samples, repeats) in waveforms {
+            builder.add_waveform::<()>(samples, repeats)?;
+        }
+        let image = builder.finish::<()>()?;
+
+        let entries = decode_header(image).expect("valid header");
+        let mut decoded = 0;
+        for (entry, (samples, repeats)) in entries.zip(waveforms) {
+            assert!(entry.voltage_time);
+            assert_eq!(entry.repeats, repeats);
+            let decoded_samples = decode_waveform(image, &entry).expect("data in image");
+            assert!(decoded_samples.eq(samples.iter().copied()));
+            decoded += 1;
+        }
+        assert_eq!(decoded, waveforms.len());
+        Ok(())
+    }
+
+    #[test]
+    fn decode_header_rejects_malformed_size_byte() {
+        // Size byte must be 3 × N - 1
+        assert!(decode_header(&[0x03, 0x80, 0x05, 0x02, 0x00]).is_none());
+        // Header runs past the image
+        assert!(decode_header(&[0x05, 0x80, 0x07, 0x02]).is_none());
+        assert!(decode_header(&[]).is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_samples_and_repeats() -> Result<()> {
+        let mut buf = [0; 16];
+        let mut builder = RamLibraryBuilder::new::<()>(&mut buf, 1)?;
+
+        assert!(matches!(
+            builder.add_waveform::<()>(&[], 0),
+            Err(Error::InvalidWaveform)
+        ));
+        assert!(matches!(
+            builder.add_waveform::<()>(&[RamSample::new(0x80, 1)], 0),
+            Err(Error::InvalidWaveform)
+        ));
+        assert!(matches!(
+            builder.add_waveform::<()>(&[RamSample::new(0x10, 1)], REPEAT_FOREVER + 1),
+            Err(Error::InvalidConfig(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn finish_requires_all_declared_waveforms() -> Result<()> {
+        let mut buf = [0; 16];
+        let mut builder = RamLibraryBuilder::new::<()>(&mut buf, 2)?;
+        builder.add_waveform::<()>(&[RamSample::new(0x10, 1)], 0)?;
+
+        assert!(matches!(
+            builder.finish::<()>(),
+            Err(Error::InvalidConfig(_))
+        ));
+        Ok(())
+    }
+}
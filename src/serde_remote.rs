@@ -0,0 +1,44 @@
+//! Serde definitions for generated register enums
+//!
+//! The enums in [`crate::ll`] are generated by `device-driver` and cannot derive serde
+//! traits, so they are mirrored here for use with `#[serde(with = "...")]`. Variants are
+//! serialized by name.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ll::OperatingMode;
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+use crate::ll::{AthFilter, AthPeakTime};
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "OperatingMode")]
+pub(crate) enum OperatingModeDef {
+    Internal,
+    ExternalEdge,
+    ExternalLevel,
+    PwmOrAnalog,
+    AudioToVibe,
+    Playback,
+    Diagnostics,
+    AutoCalibration,
+}
+
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "AthFilter")]
+pub(crate) enum AthFilterDef {
+    Hz100,
+    Hz125,
+    Hz150,
+    Hz200,
+}
+
+#[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "AthPeakTime")]
+pub(crate) enum AthPeakTimeDef {
+    Ms10,
+    Ms20,
+    Ms30,
+    Ms40,
+}
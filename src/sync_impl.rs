@@ -3,12 +3,28 @@
 //! This module contains all the synchronous methods for the DRV260X haptic driver.
 //! Methods are organized by functionality for better maintainability.
 
-use crate::ll::{FbBrakeFactor, LoopGain, OperatingMode};
+use crate::ll::{BemfGain, FbBrakeFactor, LoopGain, OperatingMode};
+#[cfg(any(feature = "drv2604l", feature = "drv2605l"))]
+use crate::ll::AutoOpenLoopCnt;
 #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
 use crate::ll::{AthFilter, AthPeakTime, LibrarySelection};
-use crate::{Drv260x, Error, StatusInfo, WaveformEntry};
+use crate::{
+    check_feedback_actuator, config, effects, sequence_timeout_us, time_offset_units, Actuator,
+    CalibrationResult, Config, Control3Fields, ControlConfig, DeviceVariant, Drv260x,
+    Drv260xBuilder, Error, Fault, FeedbackControl, SelfTestReport, Sequence, StatusInfo,
+    ThermalStatus, WaveformEntry, AUTO_CALIBRATION_TIMEOUT_US, LRA_PERIOD_STEP_US,
+    PLAYBACK_POLL_INTERVAL_MS, RESET_TIMEOUT_MS, RESET_TIME_MS, SELF_TEST_STEP_TIMEOUT_US,
+    SEQUENCER_SLOTS,
+};
 #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
-use crate::Effect;
+use crate::{playback_timeout_us, AudioToVibeConfig, Effect};
+#[cfg(feature = "bitflags")]
+use crate::StatusFlags;
+#[cfg(all(feature = "otp", any(feature = "drv2605l", feature = "drv2604l")))]
+use crate::OTP_PROGRAM_TIME_MS;
+#[cfg(any(feature = "drv2604", feature = "drv2604l"))]
+use crate::ram::RAM_SIZE;
+use embedded_hal::delay::DelayNs;
 use embedded_hal::i2c::I2c;
 
 cfg_if::cfg_if! {
@@ -28,6 +44,13 @@ where
     I2C: I2c<Error = E>,
 {
     /// Initialize the driver with basic configuration
+    ///
+    /// Verifies the device ID, leaves standby and selects internal trigger mode using only
+    /// two bus transactions (one status read and one mode write).
+    ///
+    /// Variant defaults are not applied: RATED_VOLTAGE and OD_CLAMP keep whatever value they
+    /// hold, which may have been written by earlier firmware for a different actuator. Use
+    /// [`init_with_variant_defaults`](Self::init_with_variant_defaults) to reset them.
     pub fn init(&mut self) -> Result<(), Error<E>> {
         // Read and verify device ID
         let status = self.device.status().read()?;
@@ -40,12 +63,38 @@ where
             });
         }
 
-        // Clear standby mode
-        self.device.mode().modify(|reg| reg.set_standby(false))?;
+        // Cache the detected variant for variant-dependent guards
+        self.variant = Some(DeviceVariant::from_device_id(device_id));
 
-        // Set default mode to internal trigger
-        self.set_mode(OperatingMode::Internal)?;
+        // Clear standby and select internal trigger mode in a single register write.
+        // All Mode fields are set here, so no read-modify-write is needed.
+        self.device.mode().write(|reg| {
+            reg.set_standby(false);
+            reg.set_mode(OperatingMode::Internal);
+        })?;
+        self.current_mode = Some(OperatingMode::Internal);
+
+        Ok(())
+    }
+
+    /// Initialize the driver and reset the voltage limits to conservative variant defaults
+    ///
+    /// Runs [`init`](Self::init), then writes the detected variant's reset value of
+    /// RATED_VOLTAGE ([`DeviceVariant::default_rated_voltage`]) and an OD_CLAMP that does not
+    /// exceed any variant's reset value
+    /// ([`DeviceVariant::conservative_overdrive_clamp_voltage`]). The DRV2604L/DRV2605L reset
+    /// OD_CLAMP above the standard parts' value, so their reset value is not restored as-is.
+    /// `init` alone leaves both registers untouched.
+    pub fn init_with_variant_defaults(&mut self) -> Result<(), Error<E>> {
+        self.init()?;
 
+        let variant = self.variant.unwrap_or(DeviceVariant::Unknown);
+        if let Some(voltage) = variant.default_rated_voltage() {
+            self.set_rated_voltage(voltage)?;
+        }
+        if let Some(voltage) = variant.conservative_overdrive_clamp_voltage() {
+            self.set_overdrive_clamp_voltage(voltage)?;
+        }
         Ok(())
     }
 
@@ -59,7 +108,7 @@ where
         self.init()?;
 
         // Set up for ERM actuator
-        self.set_actuator_type(false)?; // false = ERM mode
+        self.set_actuator_type(Actuator::Erm)?;
 
         // Configure for open-loop operation using low-level access
         self.device.control_3().modify(|reg| {
@@ -72,17 +121,121 @@ where
         Ok(())
     }
 
+    /// Initialize the driver for ERM actuator in closed-loop mode
+    ///
+    /// Performs device initialization, selects the ERM actuator, clears the ERM_OPEN_LOOP bit
+    /// so back-EMF feedback is used, and loads a default waveform (strong click).
+    pub fn init_closed_loop_erm(&mut self) -> Result<(), Error<E>> {
+        self.init()?;
+
+        self.set_actuator_type(Actuator::Erm)?;
+
+        self.device.control_3().modify(|reg| {
+            reg.set_erm_open_loop(false); // Closed-loop ERM
+        })?;
+
+        self.set_single_effect(1)?;
+
+        Ok(())
+    }
+
+    /// Initialize the driver for LRA actuator in closed-loop mode
+    ///
+    /// Performs device initialization, selects the LRA actuator, selects the LRA library
+    /// (DRV2605/DRV2605L only), clears the ERM_OPEN_LOOP and LRA_OPEN_LOOP bits so the
+    /// auto-resonance closed loop is used, and loads a default waveform (strong click).
+    pub fn init_closed_loop_lra(&mut self) -> Result<(), Error<E>> {
+        self.init()?;
+
+        self.set_actuator_type(Actuator::Lra)?;
+
+        #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+        self.set_library(LibrarySelection::LRA)?;
+
+        self.device.control_3().modify(|reg| {
+            reg.set_erm_open_loop(false);
+            reg.set_lra_open_loop(false); // Closed-loop LRA (auto-resonance)
+        })?;
+
+        self.set_single_effect(1)?;
+
+        Ok(())
+    }
+
+    /// Apply an actuator configuration
+    ///
+    /// Sets the actuator type, any provided rated/overdrive clamp voltages and waveform sequence,
+    /// and the operating mode.
+    pub fn configure(&mut self, config: &Config) -> Result<(), Error<E>> {
+        self.set_actuator_type(config.actuator)?;
+
+        if let Some(voltage) = config.rated_voltage {
+            self.set_rated_voltage(voltage)?;
+        }
+        if let Some(voltage) = config.overdrive_clamp_voltage {
+            self.set_overdrive_clamp_voltage(voltage)?;
+        }
+        if let Some(slots) = config.sequence {
+            self.write_sequencer_slots(&slots)?;
+        }
+
+        self.set_mode(config.mode)
+    }
+
     /// Get comprehensive device status information
     pub fn get_status(&mut self) -> Result<StatusInfo, Error<E>> {
         let status = self.device.status().read()?;
-        Ok(StatusInfo {
-            overcurrent_detected: status.oc_detect(),
-            overtemperature_detected: status.over_temp(),
-            feedback_status: status.fb_sts(),
-            diagnostic_result: status.diag_result(),
-            illegal_address: status.illegal_addr(),
-            device_id: status.device_id(),
-        })
+        Ok(StatusInfo::from(status))
+    }
+
+    /// Read the status register as typed flags
+    #[cfg(feature = "bitflags")]
+    pub fn status_flags(&mut self) -> Result<StatusFlags, Error<E>> {
+        Ok(self.get_status()?.flags())
+    }
+
+    /// Check for overcurrent or overtemperature faults
+    ///
+    /// Returns `None` if the device is healthy. Intended to be polled from a main loop
+    /// during long-running playback so playback can be stopped when a fault occurs.
+    pub fn check_faults(&mut self) -> Result<Option<Fault>, Error<E>> {
+        Ok(self.get_status()?.fault())
+    }
+
+    /// Acknowledge and clear latched fault flags
+    ///
+    /// OC_DETECT and OVER_TEMP are cleared by reading the status register, so this performs
+    /// a single status read and discards the result. Use `check_faults()` instead if the
+    /// fault that was latched is still of interest, as that read clears the flags too.
+    pub fn clear_faults(&mut self) -> Result<(), Error<E>> {
+        self.device.status().read()?;
+        Ok(())
+    }
+
+    /// Check whether the host accessed an invalid register address
+    ///
+    /// Reads the status register ILLEGAL_ADDR bit, which the device sets when a write
+    /// targets an address outside its register map, e.g. a mistyped raw access through
+    /// `device()`. The bit only exists on the DRV2604/DRV2604L (it is reserved and reads as
+    /// 0 on the DRV2605/DRV2605L).
+    pub fn check_illegal_address(&mut self) -> Result<bool, Error<E>> {
+        Ok(self.get_status()?.illegal_address)
+    }
+
+    /// Get the thermal state of the device
+    ///
+    /// Reading the status register clears the latching overtemperature flag.
+    pub fn thermal_status(&mut self) -> Result<ThermalStatus, Error<E>> {
+        Ok(self.get_status()?.thermal_status())
+    }
+
+    /// Detect the connected device variant from the status register
+    ///
+    /// Useful for firmware that supports multiple boards and needs to branch on the
+    /// actual chip at runtime, independently of the feature selected at compile time.
+    pub fn detect_variant(&mut self) -> Result<DeviceVariant, Error<E>> {
+        let status = self.device.status().read()?;
+        Ok(DeviceVariant::from_device_id(status.device_id()))
     }
 
     /// Set the operating mode
@@ -92,6 +245,19 @@ where
         Ok(())
     }
 
+    /// Set the operating mode only if it differs from the cached mode
+    ///
+    /// Returns `true` if the MODE register was written. When the mode is not known (after
+    /// construction or a reset) the register is always written.
+    pub fn ensure_mode(&mut self, mode: OperatingMode) -> Result<bool, Error<E>> {
+        if self.current_mode == Some(mode) {
+            return Ok(false);
+        }
+
+        self.set_mode(mode)?;
+        Ok(true)
+    }
+
     /// Get the current operating mode
     pub fn get_mode(&mut self) -> Result<OperatingMode, Error<E>> {
         let mode_reg = self.device.mode().read()?;
@@ -104,15 +270,74 @@ where
         Ok(())
     }
 
+    /// Enter standby, remembering the current operating mode
+    ///
+    /// The mode is taken from the driver's cache, or read from the device if it has not been
+    /// set through this driver yet. Use [`exit_standby`](Self::exit_standby) to wake up and
+    /// restore it.
+    pub fn enter_standby(&mut self) -> Result<(), Error<E>> {
+        let mode = match self.current_mode {
+            Some(mode) => mode,
+            None => self.get_mode()?,
+        };
+        self.set_standby(true)?;
+        self.standby_mode = Some(mode);
+        Ok(())
+    }
+
+    /// Exit standby and restore the mode cached by [`enter_standby`](Self::enter_standby)
+    ///
+    /// If no mode was cached (or a reset cleared it), only the standby bit is cleared.
+    /// Allow [`STANDBY_EXIT_US`](crate::ll::STANDBY_EXIT_US) before triggering playback.
+    pub fn exit_standby(&mut self) -> Result<(), Error<E>> {
+        self.set_standby(false)?;
+        if let Some(mode) = self.standby_mode.take() {
+            self.set_mode(mode)?;
+        }
+        Ok(())
+    }
+
     /// Perform device reset
     pub fn reset(&mut self) -> Result<(), Error<E>> {
         self.device.mode().modify(|reg| reg.set_dev_reset(true))?;
 
         // Clear cached state after reset
         self.current_mode = None;
+        self.variant = None;
+        self.standby_mode = None;
         Ok(())
     }
 
+    /// Reset the device and wait until it responds again
+    ///
+    /// Issues DEV_RESET, waits the reset time and then polls until DEV_RESET has cleared and
+    /// the expected device ID reads back. Bus errors while the device is still coming out
+    /// of reset are ignored. Returns `Error::Timeout` if the device is not ready within
+    /// 50 ms.
+    pub fn reset_and_wait(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<E>> {
+        self.reset()?;
+
+        let mut elapsed_ms = 0;
+        loop {
+            delay.delay_ms(RESET_TIME_MS);
+            elapsed_ms += RESET_TIME_MS;
+
+            // The device may NACK while it is still coming out of reset
+            let reset_done = matches!(self.device.mode().read(), Ok(mode) if !mode.dev_reset());
+            if reset_done {
+                if let Ok(status) = self.device.status().read() {
+                    if status.device_id() == EXPECTED_DEVICE_ID {
+                        return Ok(());
+                    }
+                }
+            }
+
+            if elapsed_ms >= RESET_TIMEOUT_MS {
+                return Err(Error::Timeout);
+            }
+        }
+    }
+
     /// Set high-impedance state
     pub fn set_high_impedance(&mut self, hi_z: bool) -> Result<(), Error<E>> {
         self.device
@@ -121,7 +346,20 @@ where
         Ok(())
     }
 
+    /// Get high-impedance state
+    ///
+    /// Returns `true` if the output stage is in high-impedance (HI_Z) state and the
+    /// actuator is not driven.
+    pub fn get_high_impedance(&mut self) -> Result<bool, Error<E>> {
+        let reg = self.device.library_selection().read()?;
+        Ok(reg.hi_z())
+    }
+
     /// Set a single waveform entry in the sequencer
+    ///
+    /// A non-wait entry with value 0 is a stop entry, so `WaveformEntry::effect(0)` ends the
+    /// sequence at `index` and the later slots never play. Use `WaveformEntry::stop()` to
+    /// make termination explicit.
     pub fn set_waveform_entry(&mut self, index: u8, entry: WaveformEntry) -> Result<(), Error<E>> {
         if index > 7 {
             return Err(Error::InvalidWaveform);
@@ -138,21 +376,86 @@ where
     }
 
     /// Set multiple waveform entries (up to 8 entries)
+    ///
+    /// All 8 sequencer slots are written in a single I2C transaction, with the slots after
+    /// the provided entries cleared to stop entries. Returns `Error::InvalidWaveform` if more
+    /// than 8 entries are given.
+    ///
+    /// A stop entry (a non-wait entry with value 0, e.g. from `WaveformEntry::effect(0)`)
+    /// ends playback, so entries after an early stop are written but never played. Use
+    /// [`set_waveform_sequence_checked`](Self::set_waveform_sequence_checked) to reject such
+    /// sequences, or
+    /// [`set_waveform_sequence_until_stop`](Self::set_waveform_sequence_until_stop) to drop
+    /// the unreachable entries.
     pub fn set_waveform_sequence(&mut self, entries: &[WaveformEntry]) -> Result<(), Error<E>> {
         if entries.len() > 8 {
             return Err(Error::InvalidWaveform);
         }
 
-        // Set provided entries
-        for (i, &entry) in entries.iter().enumerate() {
-            self.set_waveform_entry(i as u8, entry)?;
-        }
+        let mut slots = [WaveformEntry::stop(); SEQUENCER_SLOTS];
+        slots[..entries.len()].copy_from_slice(entries);
+        self.write_sequencer_slots(&slots)
+    }
 
-        // Clear remaining entries if fewer than 8 provided
-        for i in entries.len()..8 {
-            self.set_waveform_entry(i as u8, WaveformEntry::stop())?;
-        }
+    /// Set multiple waveform entries (up to 8 entries), validating the effect IDs first
+    ///
+    /// Like [`set_waveform_sequence`](Self::set_waveform_sequence), but returns
+    /// `Error::InvalidWaveform` without touching the device if an effect ID is outside the
+    /// ROM library range `1..=123`, or if a non-stop entry follows a stop entry (an effect ID
+    /// of 0 in the middle of a sequence would silently end playback).
+    pub fn set_waveform_sequence_checked(
+        &mut self,
+        entries: &[WaveformEntry],
+    ) -> Result<(), Error<E>> {
+        effects::validate_sequence(entries)?;
+        self.set_waveform_sequence(entries)
+    }
+
+    /// Set waveform entries up to the first stop entry, padding the rest with stops
+    ///
+    /// Entries after the first stop (see [`WaveformEntry::is_stop`]) are ignored, so `entries`
+    /// may be longer than 8 as long as the first stop is within the first 8. The sequencer
+    /// then never holds unreachable effects that would show up in a readback. Returns
+    /// `Error::InvalidWaveform` if more than 8 entries precede the first stop.
+    pub fn set_waveform_sequence_until_stop(
+        &mut self,
+        entries: &[WaveformEntry],
+    ) -> Result<(), Error<E>> {
+        let len = effects::playable_len(entries);
+        self.set_waveform_sequence(&entries[..len])
+    }
+
+    /// Read back all 8 waveform sequencer slots
+    ///
+    /// Each slot is reconstructed into a [`WaveformEntry`] including its wait flag, which is
+    /// useful for verifying sequencer state after a suspected glitch.
+    pub fn read_waveform_sequence(
+        &mut self,
+        out: &mut [WaveformEntry; SEQUENCER_SLOTS],
+    ) -> Result<(), Error<E>> {
+        let block = self.device.waveform_sequencer_block().read()?;
+        effects::unpack_slots(block.slots(), out);
+        Ok(())
+    }
+
+    /// Load a fixed-capacity sequence into the sequencer
+    ///
+    /// The capacity is validated when the [`Sequence`] is constructed, so unlike
+    /// `set_waveform_sequence` this cannot fail with `InvalidWaveform`. Unused slots are
+    /// filled with stop entries.
+    pub fn set_sequence<const N: usize>(&mut self, sequence: &Sequence<N>) -> Result<(), Error<E>> {
+        self.write_sequencer_slots(&sequence.to_slots())
+    }
 
+    /// Write all 8 sequencer slots in a single I2C transaction
+    fn write_sequencer_slots(
+        &mut self,
+        slots: &[WaveformEntry; SEQUENCER_SLOTS],
+    ) -> Result<(), Error<E>> {
+        let raw = effects::pack_slots(slots);
+        self.device
+            .waveform_sequencer_block()
+            .write(|reg| reg.set_slots(raw))?;
         Ok(())
     }
 
@@ -162,15 +465,150 @@ where
         self.set_waveform_sequence(&sequence)
     }
 
+    /// Play a waveform sequence of arbitrary length
+    ///
+    /// The hardware sequencer only holds 8 entries, so longer sequences are played in chunks:
+    /// each chunk of up to 8 entries is loaded, triggered with GO, and the GO bit is polled
+    /// until playback completes before the next chunk is loaded. Wait entries are played by the
+    /// sequencer as usual, and a stop entry ends the whole sequence.
+    ///
+    /// Each chunk is given a bounded time to finish, derived from its estimated duration; if
+    /// GO is still set after that, playback is stopped and `Error::Timeout` is returned.
+    ///
+    /// The device must be in [`OperatingMode::Internal`] for GO to trigger playback.
+    pub fn play_sequence(
+        &mut self,
+        entries: &[WaveformEntry],
+        delay: &mut impl DelayNs,
+    ) -> Result<(), Error<E>> {
+        // Only play up to the first stop entry
+        let end = effects::playable_len(entries);
+
+        for chunk in entries[..end].chunks(SEQUENCER_SLOTS) {
+            self.set_waveform_sequence(chunk)?;
+            self.go()?;
+
+            let played = self.wait_until_done(delay, sequence_timeout_us(chunk));
+            if matches!(played, Err(Error::Timeout)) {
+                self.stop()?;
+            }
+            played?;
+        }
+
+        Ok(())
+    }
+
+    /// Play a waveform sequence `count` times back-to-back
+    ///
+    /// Each repetition is played with [`play_sequence`](Self::play_sequence), waiting for
+    /// completion before the next one starts. A `count` of 0 plays the sequence once.
+    pub fn play_repeating(
+        &mut self,
+        entries: &[WaveformEntry],
+        count: u8,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), Error<E>> {
+        for _ in 0..count.max(1) {
+            self.play_sequence(entries, delay)?;
+        }
+        Ok(())
+    }
+
     /// Trigger playback (set GO bit)
     pub fn go(&mut self) -> Result<(), Error<E>> {
         self.device.go().write(|reg| reg.set_go(true))?;
         Ok(())
     }
 
+    /// Trigger playback after checking that the device is not in standby
+    ///
+    /// Reads the mode register first and returns `Error::NotReady` if standby is set, since
+    /// a GO write in standby is silently ignored. Costs one extra read compared to `go()`.
+    pub fn go_checked(&mut self) -> Result<(), Error<E>> {
+        if self.device.mode().read()?.standby() {
+            return Err(Error::NotReady);
+        }
+        self.go()
+    }
+
+    /// Trigger playback and verify that the GO bit latched
+    ///
+    /// Writes GO and reads it back, returning `Error::NotReady` if it is not set. This
+    /// catches writes lost on a noisy bus that a plain `go()` hides. Only use it for
+    /// playback that lasts longer than one bus transaction, otherwise GO may already have
+    /// cleared by the time it is read back.
+    pub fn trigger_verified(&mut self) -> Result<(), Error<E>> {
+        self.go()?;
+        if !self.is_active()? {
+            return Err(Error::NotReady);
+        }
+        Ok(())
+    }
+
+    /// Wait until playback completes (GO bit clears)
+    ///
+    /// Polls the GO bit every few milliseconds and returns `Error::Timeout` if it is still
+    /// set after `timeout_us` microseconds.
+    pub fn wait_until_done(
+        &mut self,
+        delay: &mut impl DelayNs,
+        timeout_us: u32,
+    ) -> Result<(), Error<E>> {
+        let mut elapsed_us: u32 = 0;
+        while self.is_active()? {
+            if elapsed_us >= timeout_us {
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(PLAYBACK_POLL_INTERVAL_MS);
+            elapsed_us = elapsed_us.saturating_add(PLAYBACK_POLL_INTERVAL_MS * 1000);
+        }
+        Ok(())
+    }
+
+    /// Trigger playback and wait until it naturally completes
+    ///
+    /// Sets the GO bit, then polls until it clears. Returns `Error::Timeout` if playback is
+    /// still active after `timeout_us` microseconds (playback is left running in that case).
+    pub fn go_and_wait(
+        &mut self,
+        delay: &mut impl DelayNs,
+        timeout_us: u32,
+    ) -> Result<(), Error<E>> {
+        self.go()?;
+        self.wait_until_done(delay, timeout_us)
+    }
+
+    /// Arm the device to play `entry` from the external IN/TRIG pin
+    ///
+    /// Loads `entry` into the first sequencer slot with a stop in the second, then selects
+    /// [`OperatingMode::ExternalLevel`] if `level_mode` is true, otherwise
+    /// [`OperatingMode::ExternalEdge`]. In edge mode a rising edge on IN/TRIG plays the
+    /// entry; in level mode it plays while the pin is held high and stops when it goes low.
+    ///
+    /// Returns `Error::InvalidWaveform` if `entry` is a stop, since the trigger would play
+    /// nothing. Toggling the IN/TRIG pin is the caller's responsibility via their own GPIO.
+    pub fn arm_external_trigger(
+        &mut self,
+        entry: WaveformEntry,
+        level_mode: bool,
+    ) -> Result<(), Error<E>> {
+        if entry.is_stop() {
+            return Err(Error::InvalidWaveform);
+        }
+        self.set_waveform_sequence(&[entry, WaveformEntry::stop()])?;
+
+        let mode = if level_mode {
+            OperatingMode::ExternalLevel
+        } else {
+            OperatingMode::ExternalEdge
+        };
+        self.set_mode(mode)
+    }
+
     /// Stop playback (clear GO bit)
     pub fn stop(&mut self) -> Result<(), Error<E>> {
         self.device.go().write(|reg| reg.set_go(false))?;
+        self.stop_pending = false;
         Ok(())
     }
 
@@ -180,6 +618,15 @@ where
         Ok(go_reg.go())
     }
 
+    /// Read the raw GO register byte without interpreting it
+    ///
+    /// Only bit 0 (GO) is defined; the remaining bits are reserved. Useful when debugging
+    /// suspected register-map issues.
+    pub fn read_go_raw(&mut self) -> Result<u8, Error<E>> {
+        let go_reg = self.device.go().read()?;
+        Ok(<[u8; 1]>::from(go_reg)[0])
+    }
+
     /// Set real-time playback input value
     pub fn set_rtp_input(&mut self, value: u8) -> Result<(), Error<E>> {
         self.device
@@ -188,110 +635,847 @@ where
         Ok(())
     }
 
-    /// Set rated voltage for calibration
-    pub fn set_rated_voltage(&mut self, voltage: u8) -> Result<(), Error<E>> {
+    /// Set real-time playback input as a signed value
+    ///
+    /// In signed RTP data format (typically used with bidirectional input), the RTP
+    /// input register is interpreted as 2's complement: positive values drive in one
+    /// direction, negative values brake/drive in the opposite direction and 0 is no drive.
+    /// The value is written as its 2's complement byte, so no manual `as u8` cast is needed.
+    ///
+    /// Returns `Error::InvalidConfig` if the device is configured for unsigned RTP data
+    /// (see [`set_rtp_data_format`](Self::set_rtp_data_format)).
+    pub fn set_rtp_input_signed(&mut self, value: i8) -> Result<(), Error<E>> {
+        let control3 = self.device.control_3().read()?;
+        if control3.data_format_rtp() {
+            return Err(Error::InvalidConfig("RTP data format is unsigned"));
+        }
+
         self.device
-            .rated_voltage()
-            .write(|reg| reg.set_rated_voltage(voltage))?;
+            .real_time_playback_input()
+            .write(|reg| reg.set_rtp_input(value as u8))?;
         Ok(())
     }
 
-    /// Set overdrive clamp voltage
-    pub fn set_overdrive_clamp_voltage(&mut self, voltage: u8) -> Result<(), Error<E>> {
-        self.device
-            .overdrive_clamp_voltage()
-            .write(|reg| reg.set_od_clamp(voltage))?;
-        Ok(())
+    /// Get the current real-time playback input value
+    pub fn get_rtp_input(&mut self) -> Result<u8, Error<E>> {
+        let reg = self.device.real_time_playback_input().read()?;
+        Ok(reg.rtp_input())
     }
 
-    /// Configure feedback control for ERM/LRA selection
-    pub fn set_actuator_type(&mut self, is_lra: bool) -> Result<(), Error<E>> {
-        self.device
-            .feedback_control()
-            .modify(|reg| reg.set_n_erm_lra(is_lra))?;
-        Ok(())
+    /// Get the current real-time playback input as a signed value
+    ///
+    /// Reinterprets the register as 2's complement, matching
+    /// [`set_rtp_input_signed`](Self::set_rtp_input_signed). Returns `Error::InvalidConfig`
+    /// if the device is configured for unsigned RTP data.
+    pub fn get_rtp_input_signed(&mut self) -> Result<i8, Error<E>> {
+        let control3 = self.device.control_3().read()?;
+        if control3.data_format_rtp() {
+            return Err(Error::InvalidConfig("RTP data format is unsigned"));
+        }
+
+        Ok(self.get_rtp_input()? as i8)
     }
 
-    /// Set feedback control parameters
-    pub fn set_feedback_control(
+    /// Linearly ramp the real-time playback input from one value to another
+    ///
+    /// Switches to [`OperatingMode::Playback`] first if the driver is not already in it, writes
+    /// `from`, then writes `steps` linearly interpolated values ending at `to`, waiting
+    /// `delay_us` microseconds before each step. With `steps == 0` the input jumps straight
+    /// to `to`.
+    pub fn ramp_rtp(
         &mut self,
-        loop_gain: LoopGain,
-        brake_factor: FbBrakeFactor,
-        bemf_gain: u8,
+        from: u8,
+        to: u8,
+        steps: u16,
+        step_delay: &mut impl DelayNs,
+        delay_us: u32,
     ) -> Result<(), Error<E>> {
-        self.device.feedback_control().modify(|reg| {
-            reg.set_loop_gain(loop_gain);
-            reg.set_fb_brake_factor(brake_factor);
-            reg.set_bemf_gain(bemf_gain & 0x3); // 2-bit field
-        })?;
+        self.ensure_mode(OperatingMode::Playback)?;
+
+        if steps == 0 {
+            return self.set_rtp_input(to);
+        }
+
+        self.set_rtp_input(from)?;
+        let span = i32::from(to) - i32::from(from);
+        for step in 1..=i32::from(steps) {
+            step_delay.delay_us(delay_us);
+            let value = i32::from(from) + span * step / i32::from(steps);
+            self.set_rtp_input(value as u8)?;
+        }
+
         Ok(())
     }
 
-    /// Set overdrive time offset for library waveforms
+    /// Stream real-time playback samples at a fixed sample period
     ///
-    /// This adds a time offset to the overdrive portion of library waveforms.
-    /// The offset is interpreted as 2's complement, so it can be positive or negative.
-    /// Overdrive Time Offset (ms) = value × PLAYBACK_INTERVAL
-    /// This register is only useful in open-loop mode.
-    pub fn set_overdrive_time_offset(&mut self, offset: i8) -> Result<(), Error<E>> {
-        self.device
-            .overdrive_time_offset()
-            .write(|reg| reg.set_odt(offset as u8))?;
-        Ok(())
+    /// Switches to [`OperatingMode::Playback`] if needed and writes each sample to the RTP input
+    /// register followed by a `sample_period_us` delay.
+    ///
+    /// In RTP mode the GO bit is neither needed to start nor able to stop playback (see
+    /// [`OperatingMode::trigger_source`]): the device drives whatever value is in the RTP input
+    /// register. So instead of setting GO and clearing it afterwards, the stream starts with
+    /// the first sample and ends by writing the no-drive value for the configured
+    /// DATA_FORMAT_RTP: 0x00 for signed data, 0x80 (mid-scale) for unsigned data. This costs
+    /// one extra Control3 read at the end of the stream.
+    ///
+    /// The stream stops at the first I2C error, which is returned as-is; the actuator keeps
+    /// driving the last written sample in that case.
+    pub fn stream_rtp(
+        &mut self,
+        samples: impl IntoIterator<Item = u8>,
+        delay: &mut impl DelayNs,
+        sample_period_us: u32,
+    ) -> Result<(), Error<E>> {
+        self.ensure_mode(OperatingMode::Playback)?;
+
+        for sample in samples {
+            self.set_rtp_input(sample)?;
+            delay.delay_us(sample_period_us);
+        }
+
+        let idle = self.rtp_idle_input()?;
+        self.set_rtp_input(idle)
     }
 
-    /// Set positive sustain time offset for library waveforms
+    /// RTP input value that produces no drive in the configured DATA_FORMAT_RTP
+    fn rtp_idle_input(&mut self) -> Result<u8, Error<E>> {
+        let unsigned = self.device.control_3().read()?.data_format_rtp();
+        Ok(if unsigned { 0x80 } else { 0x00 })
+    }
+
+    /// Select the RTP data format (signed or unsigned)
     ///
-    /// This adds a time offset to the positive sustain portion of library waveforms.
-    /// The offset is interpreted as 2's complement, so it can be positive or negative.
-    /// Sustain-Time Positive Offset (ms) = value × PLAYBACK_INTERVAL
-    pub fn set_sustain_time_offset_positive(&mut self, offset: i8) -> Result<(), Error<E>> {
+    /// This sets the Control3 DATA_FORMAT_RTP bit, which only controls how the byte written
+    /// to the RTP input register is decoded:
+    /// - `signed = true`: 2's complement, -128 (full reverse/brake) to 127 (full drive), 0 = no drive
+    /// - `signed = false`: unsigned, 0 to 255, where 0x80 is mid-scale
+    ///
+    /// It is independent of the Control2 BIDIR_INPUT bit, which selects whether the decoded
+    /// value maps to bidirectional drive (the default) or unidirectional drive (closed-loop
+    /// only, where only the positive half of the range produces output). For signed values
+    /// with [`set_rtp_input_signed`](Self::set_rtp_input_signed), keep bidirectional input enabled.
+    pub fn set_rtp_data_format(&mut self, signed: bool) -> Result<(), Error<E>> {
         self.device
-            .sustain_time_offset_pos()
-            .write(|reg| reg.set_spt(offset as u8))?;
+            .control_3()
+            .modify(|reg| reg.set_data_format_rtp(!signed))?;
         Ok(())
     }
 
-    /// Set negative sustain time offset for library waveforms
+    /// Select the input type used in PWM/analog mode
     ///
-    /// This adds a time offset to the negative sustain portion of library waveforms.
-    /// The offset is interpreted as 2's complement, so it can be positive or negative.
-    /// Sustain-Time Negative Offset (ms) = value × PLAYBACK_INTERVAL
-    pub fn set_sustain_time_offset_negative(&mut self, offset: i8) -> Result<(), Error<E>> {
+    /// This sets the Control3 N_PWM_ANALOG bit, which selects how the IN/TRIG pin is
+    /// interpreted when the device is in [`OperatingMode::PwmOrAnalog`]:
+    /// - `analog = false`: PWM input (duty cycle sets the drive level)
+    /// - `analog = true`: analog input (voltage level sets the drive level)
+    pub fn set_pwm_analog_input(&mut self, analog: bool) -> Result<(), Error<E>> {
         self.device
-            .sustain_time_offset_neg()
-            .write(|reg| reg.set_snt(offset as u8))?;
+            .control_3()
+            .modify(|reg| reg.set_n_pwm_analog(analog))?;
         Ok(())
     }
 
-    /// Set brake time offset for library waveforms
-    ///
-    /// This adds a time offset to the braking portion of library waveforms.
-    /// The offset is interpreted as 2's complement, so it can be positive or negative.
-    /// Brake Time Offset (ms) = value × PLAYBACK_INTERVAL
-    /// This register is only useful in open-loop mode.
-    pub fn set_brake_time_offset(&mut self, offset: i8) -> Result<(), Error<E>> {
+    /// Set rated voltage for calibration
+    pub fn set_rated_voltage(&mut self, voltage: u8) -> Result<(), Error<E>> {
         self.device
-            .brake_time_offset()
-            .write(|reg| reg.set_brt(offset as u8))?;
+            .rated_voltage()
+            .write(|reg| reg.set_rated_voltage(voltage))?;
         Ok(())
     }
 
-    /// Start auto-calibration process
-    pub fn start_auto_calibration(&mut self) -> Result<(), Error<E>> {
+    /// Set overdrive clamp voltage
+    pub fn set_overdrive_clamp_voltage(&mut self, voltage: u8) -> Result<(), Error<E>> {
+        self.device
+            .overdrive_clamp_voltage()
+            .write(|reg| reg.set_od_clamp(voltage))?;
+        Ok(())
+    }
+
+    /// Get the raw RATED_VOLTAGE register value
+    ///
+    /// Auto-calibration does not change this register, but OTP contents do apply to it.
+    pub fn get_rated_voltage(&mut self) -> Result<u8, Error<E>> {
+        let reg = self.device.rated_voltage().read()?;
+        Ok(reg.rated_voltage())
+    }
+
+    /// Get the raw OD_CLAMP register value
+    pub fn get_overdrive_clamp_voltage(&mut self) -> Result<u8, Error<E>> {
+        let reg = self.device.overdrive_clamp_voltage().read()?;
+        Ok(reg.od_clamp())
+    }
+
+    /// Get the rated voltage in volts
+    ///
+    /// The conversion depends on the actuator type, which is read from the feedback control
+    /// register; see [`rated_voltage_to_volts`](crate::config::rated_voltage_to_volts).
+    pub fn get_rated_voltage_volts(&mut self) -> Result<f32, Error<E>> {
+        let actuator = self.get_actuator_type()?;
+        let value = self.get_rated_voltage()?;
+        Ok(config::rated_voltage_to_volts(actuator, value))
+    }
+
+    /// Get the overdrive clamp voltage in volts
+    ///
+    /// See [`overdrive_clamp_to_volts`](crate::config::overdrive_clamp_to_volts).
+    pub fn get_overdrive_clamp_voltage_volts(&mut self) -> Result<f32, Error<E>> {
+        let value = self.get_overdrive_clamp_voltage()?;
+        Ok(config::overdrive_clamp_to_volts(value))
+    }
+
+    /// Select the actuator type (N_ERM_LRA bit in the feedback control register)
+    pub fn set_actuator_type(&mut self, actuator: Actuator) -> Result<(), Error<E>> {
+        self.device
+            .feedback_control()
+            .modify(|reg| reg.set_n_erm_lra(actuator == Actuator::Lra))?;
+        Ok(())
+    }
+
+    /// Select the actuator type from a bool (`true` = LRA, `false` = ERM)
+    #[deprecated(note = "use `set_actuator_type` with an `Actuator`")]
+    pub fn set_actuator_type_bool(&mut self, is_lra: bool) -> Result<(), Error<E>> {
+        self.device
+            .feedback_control()
+            .modify(|reg| reg.set_n_erm_lra(is_lra))?;
+        Ok(())
+    }
+
+    /// Get the actuator type selected in the feedback control register
+    pub fn get_actuator_type(&mut self) -> Result<Actuator, Error<E>> {
+        let reg = self.device.feedback_control().read()?;
+        Ok(if reg.n_erm_lra() {
+            Actuator::Lra
+        } else {
+            Actuator::Erm
+        })
+    }
+
+    /// Select open-loop or closed-loop operation for the given actuator type
+    ///
+    /// ERM and LRA loop modes are controlled by separate Control3 bits (ERM_OPEN_LOOP and
+    /// LRA_OPEN_LOOP); this writes the correct one for `actuator` and leaves the other untouched.
+    pub fn set_loop_mode(&mut self, actuator: Actuator, open_loop: bool) -> Result<(), Error<E>> {
+        self.device.control_3().modify(|reg| match actuator {
+            Actuator::Erm => reg.set_erm_open_loop(open_loop),
+            Actuator::Lra => reg.set_lra_open_loop(open_loop),
+        })?;
+        Ok(())
+    }
+
+    /// Check whether open-loop operation is selected for the given actuator type
+    ///
+    /// Reads the Control3 ERM_OPEN_LOOP or LRA_OPEN_LOOP bit for `actuator`.
+    pub fn get_loop_mode(&mut self, actuator: Actuator) -> Result<bool, Error<E>> {
+        let control3 = self.device.control_3().read()?;
+        Ok(match actuator {
+            Actuator::Erm => control3.erm_open_loop(),
+            Actuator::Lra => control3.lra_open_loop(),
+        })
+    }
+
+    /// Select the LRA drive mode (once or twice per resonance cycle)
+    ///
+    /// This sets the Control3 LRA_DRIVE_MODE bit. In closed-loop LRA operation the device
+    /// tracks the actuator's resonance (reported in the LRA resonance period register) and
+    /// updates the drive amplitude on that cycle:
+    /// - `twice_per_cycle = false`: amplitude updated once per resonance period (default)
+    /// - `twice_per_cycle = true`: amplitude updated every half period, giving smoother
+    ///   amplitude transitions at the cost of more frequent back-EMF sampling
+    ///
+    /// Because updates are locked to the measured period, the effective update rate follows
+    /// the actuator's resonance frequency rather than a fixed clock.
+    pub fn set_lra_drive_mode(&mut self, twice_per_cycle: bool) -> Result<(), Error<E>> {
+        self.device
+            .control_3()
+            .modify(|reg| reg.set_lra_drive_mode(twice_per_cycle))?;
+        Ok(())
+    }
+
+    /// Configure the LRA automatic transition from auto-resonance to open loop
+    ///
+    /// With `Some(attempts)`, the device falls back to open-loop drive at the
+    /// LRA_OPEN_LOOP_PERIOD frequency if it fails to lock onto the actuator's resonance
+    /// within the given number of synchronization attempts (Control5 LRA_AUTO_OPEN_LOOP and
+    /// AUTO_OL_CNT). `None` disables the fallback and leaves the attempt count untouched.
+    ///
+    /// Only available on the DRV2604L and DRV2605L.
+    #[cfg(any(feature = "drv2604l", feature = "drv2605l"))]
+    pub fn set_lra_open_loop_resonance(
+        &mut self,
+        attempts: Option<AutoOpenLoopCnt>,
+    ) -> Result<(), Error<E>> {
+        self.device.control_5().modify(|reg| {
+            reg.set_lra_auto_open_loop(attempts.is_some());
+            if let Some(attempts) = attempts {
+                reg.set_auto_ol_cnt(attempts);
+            }
+        })?;
+        Ok(())
+    }
+
+    /// Get the LRA automatic open-loop fallback setting
+    ///
+    /// Returns the attempt count if the fallback is enabled, or `None` if it is disabled.
+    #[cfg(any(feature = "drv2604l", feature = "drv2605l"))]
+    pub fn get_lra_open_loop_resonance(&mut self) -> Result<Option<AutoOpenLoopCnt>, Error<E>> {
+        let control5 = self.device.control_5().read()?;
+        Ok(control5
+            .lra_auto_open_loop()
+            .then(|| control5.auto_ol_cnt()))
+    }
+
+    /// Enable or disable the startup boost
+    ///
+    /// Sets the Control1 STARTUP_BOOST bit, which applies a higher loop gain during
+    /// overdrive to give a stronger initial kick, useful for sluggish ERM motors.
+    pub fn set_startup_boost(&mut self, enabled: bool) -> Result<(), Error<E>> {
+        self.device
+            .control_1()
+            .modify(|reg| reg.set_startup_boost(enabled))?;
+        Ok(())
+    }
+
+    /// Check whether the startup boost is enabled
+    pub fn get_startup_boost(&mut self) -> Result<bool, Error<E>> {
+        let control1 = self.device.control_1().read()?;
+        Ok(control1.startup_boost())
+    }
+
+    /// Enable or disable supply compensation
+    ///
+    /// With compensation enabled (the default), the drive level is adjusted for supply
+    /// voltage variation so effects feel consistent as a battery discharges. Writes the
+    /// inverse of the Control3 SUPPLY_COMP_DIS bit.
+    pub fn set_supply_compensation(&mut self, enabled: bool) -> Result<(), Error<E>> {
+        self.device
+            .control_3()
+            .modify(|reg| reg.set_supply_comp_dis(!enabled))?;
+        Ok(())
+    }
+
+    /// Check whether supply compensation is enabled
+    pub fn get_supply_compensation(&mut self) -> Result<bool, Error<E>> {
+        let control3 = self.device.control_3().read()?;
+        Ok(!control3.supply_comp_dis())
+    }
+
+    /// Set the ERM/LRA loop modes, RTP data format, input type and supply compensation
+    ///
+    /// Updates all five Control3 options in a single read-modify-write cycle instead of one
+    /// per field. The noise gate threshold and LRA drive mode are preserved.
+    pub fn set_control3_fields(&mut self, fields: Control3Fields) -> Result<(), Error<E>> {
+        self.device.control_3().modify(|reg| {
+            reg.set_erm_open_loop(fields.erm_open_loop);
+            reg.set_lra_open_loop(fields.lra_open_loop);
+            reg.set_data_format_rtp(!fields.rtp_signed);
+            reg.set_n_pwm_analog(fields.analog_input);
+            reg.set_supply_comp_dis(!fields.supply_compensation);
+        })?;
+        Ok(())
+    }
+
+    /// Read Control1-Control4 into a typed snapshot
+    ///
+    /// Reads the four registers one at a time, so the snapshot is only consistent if nothing
+    /// else writes them in between.
+    pub fn get_control_config(&mut self) -> Result<ControlConfig, Error<E>> {
+        let control1 = self.device.control_1().read()?;
+        let control2 = self.device.control_2().read()?;
+        let control3 = self.device.control_3().read()?;
+        let control4 = self.device.control_4().read()?;
+
+        Ok(ControlConfig {
+            drive_time: control1.drive_time(),
+            ac_couple: control1.ac_couple(),
+            startup_boost: control1.startup_boost(),
+            idiss_time: control2.idiss_time(),
+            blanking_time: control2.blanking_time(),
+            sample_time: control2.sample_time(),
+            brake_stabilizer: control2.brake_stabilizer(),
+            bidirectional_input: control2.bidir_input(),
+            lra_open_loop: control3.lra_open_loop(),
+            analog_input: control3.n_pwm_analog(),
+            lra_drive_twice_per_cycle: control3.lra_drive_mode(),
+            rtp_signed: !control3.data_format_rtp(),
+            supply_compensation: !control3.supply_comp_dis(),
+            erm_open_loop: control3.erm_open_loop(),
+            noise_gate: control3.ng_thresh(),
+            otp_programmed: control4.otp_status(),
+            auto_cal_time: control4.auto_cal_time(),
+            zero_cross_time: control4.zc_det_time(),
+        })
+    }
+
+    /// Set feedback control parameters
+    ///
+    /// `bemf_gain` is masked to the 2-bit field. Prefer
+    /// [`set_feedback_control_typed`](Self::set_feedback_control_typed), which takes a
+    /// [`BemfGain`].
+    pub fn set_feedback_control(
+        &mut self,
+        loop_gain: LoopGain,
+        brake_factor: FbBrakeFactor,
+        bemf_gain: u8,
+    ) -> Result<(), Error<E>> {
+        self.set_feedback_control_typed(loop_gain, brake_factor, BemfGain::from(bemf_gain & 0x3))
+    }
+
+    /// Set feedback control parameters with a typed BEMF gain
+    ///
+    /// The meaning of `bemf_gain` depends on the actuator type, see [`BemfGain`].
+    pub fn set_feedback_control_typed(
+        &mut self,
+        loop_gain: LoopGain,
+        brake_factor: FbBrakeFactor,
+        bemf_gain: BemfGain,
+    ) -> Result<(), Error<E>> {
+        self.device.feedback_control().modify(|reg| {
+            reg.set_loop_gain(loop_gain);
+            reg.set_fb_brake_factor(brake_factor);
+            reg.set_bemf_gain(bemf_gain);
+        })?;
+        Ok(())
+    }
+
+    /// Set feedback control parameters with a raw BEMF gain value
+    ///
+    /// Like [`set_feedback_control`](Self::set_feedback_control), but returns
+    /// `Error::InvalidConfig` if `bemf_gain` does not fit the 2-bit field instead of
+    /// masking it.
+    pub fn set_feedback_control_raw(
+        &mut self,
+        loop_gain: LoopGain,
+        brake_factor: FbBrakeFactor,
+        bemf_gain: u8,
+    ) -> Result<(), Error<E>> {
+        if bemf_gain > 0x3 {
+            return Err(Error::InvalidConfig("BEMF gain exceeds 2 bits"));
+        }
+        self.set_feedback_control_typed(loop_gain, brake_factor, BemfGain::from(bemf_gain))
+    }
+
+    /// Set feedback control parameters tuned for `actuator`
+    ///
+    /// Like [`set_feedback_control_typed`](Self::set_feedback_control_typed), but returns
+    /// `Error::InvalidConfig` without writing if the device is configured for the other
+    /// actuator type, since the BEMF gain scale depends on it (see [`BemfGain`]). Range
+    /// checking of a raw BEMF gain is done by
+    /// [`set_feedback_control_raw`](Self::set_feedback_control_raw).
+    pub fn set_feedback_control_checked(
+        &mut self,
+        actuator: Actuator,
+        loop_gain: LoopGain,
+        brake_factor: FbBrakeFactor,
+        bemf_gain: BemfGain,
+    ) -> Result<(), Error<E>> {
+        let feedback = self.device.feedback_control().read()?;
+        check_feedback_actuator(feedback.n_erm_lra(), actuator)?;
+
+        self.device.feedback_control().write(|reg| {
+            *reg = feedback;
+            reg.set_loop_gain(loop_gain);
+            reg.set_fb_brake_factor(brake_factor);
+            reg.set_bemf_gain(bemf_gain);
+        })?;
+        Ok(())
+    }
+
+    /// Read back feedback control parameters
+    ///
+    /// Useful to verify that auto-calibration (which updates the BEMF gain) did not clobber
+    /// the configured settings.
+    pub fn get_feedback_control(&mut self) -> Result<FeedbackControl, Error<E>> {
+        let reg = self.device.feedback_control().read()?;
+        Ok(FeedbackControl {
+            loop_gain: reg.loop_gain(),
+            brake_factor: reg.fb_brake_factor(),
+            bemf_gain: reg.bemf_gain(),
+            actuator: if reg.n_erm_lra() {
+                Actuator::Lra
+            } else {
+                Actuator::Erm
+            },
+        })
+    }
+
+    /// Get the measured LRA resonance frequency in Hz
+    ///
+    /// Converts the LRA_PERIOD register (98.46 µs per LSB) to a frequency. The measurement
+    /// is only meaningful while an LRA is being driven in closed-loop (auto-resonance)
+    /// mode; returns `Error::NotReady` if no period has been measured yet.
+    pub fn get_lra_frequency_hz(&mut self) -> Result<f32, Error<E>> {
+        let period = self.device.lra_resonance_period().read()?.lra_period();
+        if period == 0 {
+            return Err(Error::NotReady);
+        }
+        Ok(1_000_000.0 / (f32::from(period) * LRA_PERIOD_STEP_US))
+    }
+
+    /// Check whether the closed-loop feedback is tracking the actuator
+    ///
+    /// Returns the inverse of the status register FB_STS bit, which the device sets when the
+    /// feedback controller times out waiting for back-EMF, e.g. when an LRA's auto-resonance
+    /// fails to lock or the actuator is disconnected. A lock failure shows up as weak output;
+    /// compare [`get_lra_frequency_hz`](Self::get_lra_frequency_hz) against the actuator's
+    /// rated resonance to tell a detuned LRA from a dead one. FB_STS is a debug flag and may
+    /// also be set briefly after long braking periods, so check it while driving the
+    /// actuator. Reading the status register clears its latching fault flags.
+    ///
+    /// Only available on the DRV2604 and DRV2605; the bit is reserved on the L variants.
+    #[cfg(any(feature = "drv2604", feature = "drv2605"))]
+    pub fn is_feedback_locked(&mut self) -> Result<bool, Error<E>> {
+        Ok(!self.get_status()?.feedback_status)
+    }
+
+    /// Set overdrive time offset for library waveforms
+    ///
+    /// This adds a time offset to the overdrive portion of library waveforms.
+    /// The offset is interpreted as 2's complement, so it can be positive or negative.
+    /// Overdrive Time Offset (ms) = value × PLAYBACK_INTERVAL
+    /// This register is only useful in open-loop mode.
+    pub fn set_overdrive_time_offset(&mut self, offset: i8) -> Result<(), Error<E>> {
+        self.device
+            .overdrive_time_offset()
+            .write(|reg| reg.set_odt(offset as u8))?;
+        Ok(())
+    }
+
+    /// Set positive sustain time offset for library waveforms
+    ///
+    /// This adds a time offset to the positive sustain portion of library waveforms.
+    /// The offset is interpreted as 2's complement, so it can be positive or negative.
+    /// Sustain-Time Positive Offset (ms) = value × PLAYBACK_INTERVAL
+    pub fn set_sustain_time_offset_positive(&mut self, offset: i8) -> Result<(), Error<E>> {
+        self.device
+            .sustain_time_offset_pos()
+            .write(|reg| reg.set_spt(offset as u8))?;
+        Ok(())
+    }
+
+    /// Set negative sustain time offset for library waveforms
+    ///
+    /// This adds a time offset to the negative sustain portion of library waveforms.
+    /// The offset is interpreted as 2's complement, so it can be positive or negative.
+    /// Sustain-Time Negative Offset (ms) = value × PLAYBACK_INTERVAL
+    pub fn set_sustain_time_offset_negative(&mut self, offset: i8) -> Result<(), Error<E>> {
+        self.device
+            .sustain_time_offset_neg()
+            .write(|reg| reg.set_snt(offset as u8))?;
+        Ok(())
+    }
+
+    /// Set brake time offset for library waveforms
+    ///
+    /// This adds a time offset to the braking portion of library waveforms.
+    /// The offset is interpreted as 2's complement, so it can be positive or negative.
+    /// Brake Time Offset (ms) = value × PLAYBACK_INTERVAL
+    /// This register is only useful in open-loop mode.
+    pub fn set_brake_time_offset(&mut self, offset: i8) -> Result<(), Error<E>> {
+        self.device
+            .brake_time_offset()
+            .write(|reg| reg.set_brt(offset as u8))?;
+        Ok(())
+    }
+
+    /// Set the overdrive, sustain and brake time offsets in one transaction
+    ///
+    /// Writes the four contiguous offset registers (ODT, SPT, SNT, BRT) as a single block,
+    /// which halves bus traffic compared to the individual setters while tuning.
+    pub fn set_timing_offsets(
+        &mut self,
+        overdrive: i8,
+        sustain_pos: i8,
+        sustain_neg: i8,
+        brake: i8,
+    ) -> Result<(), Error<E>> {
+        let raw = u32::from_le_bytes([
+            overdrive as u8,
+            sustain_pos as u8,
+            sustain_neg as u8,
+            brake as u8,
+        ]);
+        self.device
+            .time_offset_block()
+            .write(|reg| reg.set_offsets(raw))?;
+        Ok(())
+    }
+
+    /// Set overdrive time offset in milliseconds
+    ///
+    /// Converts `ms` to units of `playback_interval_us` (1000 or 5000, matching the
+    /// PLAYBACK_INTERVAL setting), rounding to the nearest unit. Offsets beyond ±127 units
+    /// saturate, so e.g. more than ±635 ms at a 5 ms interval is silently clamped.
+    pub fn set_overdrive_time_offset_ms(
+        &mut self,
+        ms: i16,
+        playback_interval_us: u16,
+    ) -> Result<(), Error<E>> {
+        self.set_overdrive_time_offset(time_offset_units(ms, playback_interval_us))
+    }
+
+    /// Set positive sustain time offset in milliseconds
+    ///
+    /// See [`set_overdrive_time_offset_ms`](Self::set_overdrive_time_offset_ms) for the
+    /// unit conversion and saturation.
+    pub fn set_sustain_time_offset_positive_ms(
+        &mut self,
+        ms: i16,
+        playback_interval_us: u16,
+    ) -> Result<(), Error<E>> {
+        self.set_sustain_time_offset_positive(time_offset_units(ms, playback_interval_us))
+    }
+
+    /// Set negative sustain time offset in milliseconds
+    ///
+    /// See [`set_overdrive_time_offset_ms`](Self::set_overdrive_time_offset_ms) for the
+    /// unit conversion and saturation.
+    pub fn set_sustain_time_offset_negative_ms(
+        &mut self,
+        ms: i16,
+        playback_interval_us: u16,
+    ) -> Result<(), Error<E>> {
+        self.set_sustain_time_offset_negative(time_offset_units(ms, playback_interval_us))
+    }
+
+    /// Set brake time offset in milliseconds
+    ///
+    /// See [`set_overdrive_time_offset_ms`](Self::set_overdrive_time_offset_ms) for the
+    /// unit conversion and saturation.
+    pub fn set_brake_time_offset_ms(
+        &mut self,
+        ms: i16,
+        playback_interval_us: u16,
+    ) -> Result<(), Error<E>> {
+        self.set_brake_time_offset(time_offset_units(ms, playback_interval_us))
+    }
+
+    /// Check whether OTP memory has been programmed
+    ///
+    /// Reads the Control4 OTP_STATUS bit. Firmware can use this at boot to decide whether to
+    /// run auto-calibration or trust the values burned into OTP.
+    pub fn is_otp_programmed(&mut self) -> Result<bool, Error<E>> {
+        let control4 = self.device.control_4().read()?;
+        Ok(control4.otp_status())
+    }
+
+    /// Start auto-calibration process
+    pub fn start_auto_calibration(&mut self) -> Result<(), Error<E>> {
         // Set mode to auto-calibration
         self.set_mode(OperatingMode::AutoCalibration)?;
         // Trigger calibration
         self.go()
     }
 
+    /// Run auto-calibration, wait for it to finish and verify the result
+    ///
+    /// Starts calibration with the current calibration settings (actuator type, rated and
+    /// overdrive clamp voltage, feedback control and AUTO_CAL_TIME) and waits until GO
+    /// clears. Returns `Error::CalibrationFailed` with the status register contents if
+    /// DIAG_RESULT is set or a fault latched, and `Error::Timeout` (after stopping) if
+    /// calibration does not finish within 2 s. The device is left in auto-calibration mode.
+    pub fn run_auto_calibration(
+        &mut self,
+        delay: &mut impl DelayNs,
+    ) -> Result<CalibrationResult, Error<E>> {
+        self.start_auto_calibration()?;
+        if let Err(e) = self.wait_until_done(delay, AUTO_CALIBRATION_TIMEOUT_US) {
+            if matches!(e, Error::Timeout) {
+                self.stop()?;
+            }
+            return Err(e);
+        }
+
+        let status = self.get_status()?;
+        if status.diagnostic_result || status.fault().is_some() {
+            return Err(Error::CalibrationFailed(status));
+        }
+
+        Ok(CalibrationResult {
+            compensation: self.device.auto_calib_comp_result().read()?.a_cal_comp(),
+            back_emf: self
+                .device
+                .auto_calib_back_emf_result()
+                .read()?
+                .a_cal_bemf(),
+            bemf_gain: self.device.feedback_control().read()?.bemf_gain(),
+        })
+    }
+
     /// Start diagnostics process
+    ///
+    /// When GO clears, the only result is the DIAG_RESULT bit in the status register (see
+    /// [`StatusInfo::diagnostic_result`]): set if the actuator is open, shorted, or did not
+    /// move as expected. The DRV260X does not measure or report actuator impedance; to track
+    /// actuator health over time, compare the auto-calibration results (A_CAL_COMP and
+    /// A_CAL_BEMF) between runs instead.
     pub fn start_diagnostics(&mut self) -> Result<(), Error<E>> {
         // Set mode to diagnostics
         self.set_mode(OperatingMode::Diagnostics)?;
         // Trigger diagnostics
         self.go()
     }
+
+    /// Check whether diagnostics or auto-calibration is still in progress
+    ///
+    /// Reads the mode register and the GO bit. The device clears GO when either routine
+    /// completes but leaves MODE unchanged, so this returns `true` only while MODE is
+    /// diagnostics or auto-calibration and GO is still set. Unlike polling GO alone, it
+    /// does not mistake ordinary playback for a running special mode.
+    pub fn is_special_mode_running(&mut self) -> Result<bool, Error<E>> {
+        let mode = self.get_mode()?;
+        if !matches!(
+            mode,
+            OperatingMode::Diagnostics | OperatingMode::AutoCalibration
+        ) {
+            return Ok(false);
+        }
+        self.is_active()
+    }
+
+    /// Run a factory self-test of the device and actuator
+    ///
+    /// Performs the following steps and reports the outcome of each:
+    /// 1. Checks the device ID against the enabled device feature
+    /// 2. Runs diagnostics and checks DIAG_RESULT
+    /// 3. Optionally runs auto-calibration (with the current calibration settings)
+    /// 4. Plays waveform ID 1 from internal trigger mode and checks for faults
+    ///
+    /// Steps after a failed device ID check are skipped. A step that does not complete
+    /// within 2 s is stopped and reported as failed. The device is left in internal trigger
+    /// mode; the sequencer is overwritten by the test effect.
+    pub fn self_test(
+        &mut self,
+        delay: &mut impl DelayNs,
+        run_calibration: bool,
+    ) -> Result<SelfTestReport, Error<E>> {
+        let mut report = SelfTestReport {
+            device_id_ok: self.get_status()?.device_id == EXPECTED_DEVICE_ID,
+            diagnostics_passed: false,
+            calibration_passed: None,
+            effect_played: false,
+        };
+        if !report.device_id_ok {
+            return Ok(report);
+        }
+
+        self.set_standby(false)?;
+
+        self.start_diagnostics()?;
+        report.diagnostics_passed = self.finish_self_test_step(delay)?;
+
+        if run_calibration {
+            self.start_auto_calibration()?;
+            report.calibration_passed = Some(self.finish_self_test_step(delay)?);
+        }
+
+        self.set_mode(OperatingMode::Internal)?;
+        self.set_single_effect(1)?;
+        self.go()?;
+        report.effect_played = self.finish_self_test_step(delay)?;
+
+        Ok(report)
+    }
+
+    /// Wait for a self-test step to finish and check the status register for failures
+    fn finish_self_test_step(&mut self, delay: &mut impl DelayNs) -> Result<bool, Error<E>> {
+        match self.wait_until_done(delay, SELF_TEST_STEP_TIMEOUT_US) {
+            Ok(()) => {}
+            Err(Error::Timeout) => {
+                self.stop()?;
+                return Ok(false);
+            }
+            Err(e) => return Err(e),
+        }
+
+        let status = self.get_status()?;
+        Ok(!status.diagnostic_result && status.fault().is_none())
+    }
+}
+
+impl<I2C, E> Drv260xBuilder<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Build the driver, verify the device ID and apply the accumulated configuration
+    pub fn build_and_init(self) -> Result<Drv260x<I2C>, Error<E>> {
+        let (mut driver, config) = self.build();
+        driver.init()?;
+        driver.configure(&config)?;
+        Ok(driver)
+    }
+}
+
+/// One-time programmable (OTP) memory support for DRV2604L and DRV2605L.
+#[cfg(all(feature = "otp", any(feature = "drv2605l", feature = "drv2604l")))]
+impl<I2C, E> Drv260x<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Burn the current calibration/configuration registers into OTP memory
+    ///
+    /// # Warning
+    ///
+    /// **OTP memory can only be programmed ONCE.** This permanently stores registers
+    /// 0x16 to 0x1A (rated voltage, overdrive clamp, auto-calibration results and feedback
+    /// control) and cannot be undone or repeated. Run and verify auto-calibration first.
+    ///
+    /// The datasheet requires VDD to be 4.2 V (±5%) during programming; programming at any
+    /// other supply voltage may fail or leave the memory in an undefined state.
+    ///
+    /// Checks that OTP_STATUS is clear, sets the OTP_PROGRAM bit, waits for programming to
+    /// complete and verifies the OTP_STATUS bit. Returns `Error::InvalidConfig` without
+    /// touching OTP_PROGRAM if the memory was already programmed, and `Error::NotReady` if
+    /// OTP_STATUS is not set afterwards.
+    pub fn program_otp(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<E>> {
+        let control4 = self.device.control_4().read()?;
+        if control4.otp_status() {
+            return Err(Error::InvalidConfig("OTP memory already programmed"));
+        }
+
+        self.device.control_4().write(|reg| {
+            *reg = control4;
+            reg.set_otp_program(true);
+        })?;
+
+        delay.delay_ms(OTP_PROGRAM_TIME_MS);
+
+        let control4 = self.device.control_4().read()?;
+        if !control4.otp_status() {
+            return Err(Error::NotReady);
+        }
+
+        Ok(())
+    }
+}
+
+/// Waveform RAM access for DRV2604 and DRV2604L.
+#[cfg(any(feature = "drv2604", feature = "drv2604l"))]
+impl<I2C, E> Drv260x<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Write a waveform library image to the waveform RAM, starting at address 0
+    ///
+    /// `data` is typically the output of [`RamLibraryBuilder::finish`](crate::ram::RamLibraryBuilder::finish). The RAM address
+    /// auto-increments after each data byte, so the image is written one byte per register
+    /// write. Returns `Error::InvalidConfig` if the image is larger than [`RAM_SIZE`].
+    pub fn write_ram_waveforms(&mut self, data: &[u8]) -> Result<(), Error<E>> {
+        if data.len() > RAM_SIZE {
+            return Err(Error::InvalidConfig("RAM image exceeds waveform RAM size"));
+        }
+
+        self.device
+            .ram_address_upper_byte()
+            .write(|reg| reg.set_ram_addr_ub(0))?;
+        self.device
+            .ram_address_lower_byte()
+            .write(|reg| reg.set_ram_addr_lb(0))?;
+
+        for &byte in data {
+            self.device.ram_data().write(|reg| reg.set_ram_data(byte))?;
+        }
+        Ok(())
+    }
 }
 
 /// Methods only available on DRV2605 and DRV2605L variants (ROM library and audio-to-vibe).
@@ -302,18 +1486,179 @@ where
 {
     /// Set library selection
     pub fn set_library(&mut self, library: LibrarySelection) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         self.device
             .library_selection()
             .modify(|reg| reg.set_library_sel(library))?;
         Ok(())
     }
 
+    /// Get the current library selection
+    pub fn get_library(&mut self) -> Result<LibrarySelection, Error<E>> {
+        self.ensure_rom_library()?;
+
+        let reg = self.device.library_selection().read()?;
+        Ok(reg.library_sel())
+    }
+
+    /// Select the ROM library suited to an actuator and rated voltage
+    ///
+    /// Uses [`config::library_for_actuator`] and returns the library that was written.
+    pub fn set_library_for_actuator(
+        &mut self,
+        actuator: Actuator,
+        rated_voltage_volts: f32,
+    ) -> Result<LibrarySelection, Error<E>> {
+        let library = config::library_for_actuator(actuator, rated_voltage_volts);
+        self.set_library(library)?;
+        Ok(library)
+    }
+
     /// Set a single predefined effect in the first sequencer slot
     pub fn set_single_effect_enum(&mut self, effect: Effect) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         let sequence = [WaveformEntry::from(effect), WaveformEntry::stop()];
         self.set_waveform_sequence(&sequence)
     }
 
+    /// Load a single predefined effect, trigger playback and return its nominal duration
+    ///
+    /// Returns immediately after setting GO with the approximate duration in milliseconds
+    /// from [`Effect::duration_ms`], so the caller knows how long to wait before the next action.
+    pub fn play_effect(&mut self, effect: Effect) -> Result<u16, Error<E>> {
+        self.ensure_rom_library()?;
+
+        self.set_single_effect_enum(effect)?;
+        self.go()?;
+        Ok(effect.duration_ms())
+    }
+
+    /// Apply the effect's recommended brake factor, then play it
+    ///
+    /// Behaves like [`play_effect`](Self::play_effect), but first updates the
+    /// FB_BRAKE_FACTOR field if [`Effect::recommended_brake_factor`] returns a value. The
+    /// other feedback settings are untouched, and the new factor stays in effect after
+    /// playback.
+    pub fn play_effect_tuned(&mut self, effect: Effect) -> Result<u16, Error<E>> {
+        self.ensure_rom_library()?;
+
+        if let Some(brake_factor) = effect.recommended_brake_factor() {
+            self.device
+                .feedback_control()
+                .modify(|reg| reg.set_fb_brake_factor(brake_factor))?;
+        }
+        self.play_effect(effect)
+    }
+
+    /// Play a predefined effect once and restore the previous operating mode
+    ///
+    /// Switches to internal trigger mode, plays `effect` and waits for it to complete, then
+    /// returns to the mode that was active before (e.g. RTP or audio-to-vibe), so one-off
+    /// effects do not disturb an otherwise configured device. The previous mode is restored
+    /// even if playback or stopping fails, and the first error is returned; `Error::Timeout`
+    /// is returned (after stopping playback) if the effect runs far beyond its nominal
+    /// duration.
+    pub fn play_effect_once(
+        &mut self,
+        effect: Effect,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
+        let previous_mode = match self.current_mode {
+            Some(mode) => mode,
+            None => self.get_mode()?,
+        };
+        self.set_mode(OperatingMode::Internal)?;
+
+        let played = match self.play_effect(effect) {
+            Ok(duration_ms) => self.wait_until_done(delay, playback_timeout_us(duration_ms)),
+            Err(e) => Err(e),
+        };
+        let stopped = if matches!(played, Err(Error::Timeout)) {
+            self.stop()
+        } else {
+            Ok(())
+        };
+
+        // Restore the mode even if stopping failed, then report the first error
+        let restored = self.set_mode(previous_mode);
+        played.and(stopped).and(restored)
+    }
+
+    /// Play a strong click and wait for it to finish
+    ///
+    /// Shorthand for the common button-feedback case: switches to internal trigger mode if
+    /// the driver is not already in it, plays [`Effect::StrongClick100`] and waits until GO
+    /// clears. Unlike [`play_effect_once`](Self::play_effect_once), the device is left in
+    /// internal trigger mode afterwards.
+    pub fn quick_click(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
+        self.ensure_mode(OperatingMode::Internal)?;
+
+        let duration_ms = self.play_effect(Effect::StrongClick100)?;
+        let played = self.wait_until_done(delay, playback_timeout_us(duration_ms));
+        if matches!(played, Err(Error::Timeout)) {
+            self.stop()?;
+        }
+        played
+    }
+
+    /// Play a predefined effect, wait for it to finish and enter standby
+    ///
+    /// Low-power idiom for battery products: wakes the device into internal trigger mode,
+    /// plays `effect`, waits for completion and then calls
+    /// [`enter_standby`](Self::enter_standby), so a later `exit_standby()` returns to
+    /// internal trigger mode. Standby is entered even if playback fails; on
+    /// `Error::Timeout` playback is stopped first and the timeout is still returned.
+    pub fn play_and_standby(
+        &mut self,
+        effect: Effect,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
+        self.device.mode().modify(|reg| {
+            reg.set_standby(false);
+            reg.set_mode(OperatingMode::Internal);
+        })?;
+        self.current_mode = Some(OperatingMode::Internal);
+
+        let played = match self.play_effect(effect) {
+            Ok(duration_ms) => self.wait_until_done(delay, playback_timeout_us(duration_ms)),
+            Err(e) => Err(e),
+        };
+        let stopped = if matches!(played, Err(Error::Timeout)) {
+            self.stop()
+        } else {
+            Ok(())
+        };
+
+        self.enter_standby()?;
+        stopped?;
+        played
+    }
+
+    /// Load predefined effects separated by a wait of `gap_ms` milliseconds
+    ///
+    /// The effects and waits are interleaved (`effect, wait, effect, ...`) without a trailing
+    /// wait, so at most 4 effects fit in the 8 sequencer slots; a gap that rounds to 0 adds
+    /// no wait entries. Returns `Error::InvalidWaveform` if the sequence does not fit or
+    /// `gap_ms` exceeds [`WaveformEntry::MAX_WAIT_MS`].
+    pub fn set_effects_with_gaps(
+        &mut self,
+        effects: &[Effect],
+        gap_ms: u16,
+    ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
+        let slots = effects::interleave_gaps(effects, gap_ms)?;
+        self.write_sequencer_slots(&slots)
+    }
+
     /// Configure audio-to-vibe control settings
     ///
     /// This method configures the audio-to-haptic conversion filter and peak time settings.
@@ -322,6 +1667,8 @@ where
         filter: AthFilter,
         peak_time: AthPeakTime,
     ) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         self.device.audio_to_vibe_control().modify(|reg| {
             reg.set_ath_filter(filter);
             reg.set_ath_peak_time(peak_time);
@@ -329,10 +1676,20 @@ where
         Ok(())
     }
 
+    /// Get the audio-to-vibe filter and peak time settings
+    pub fn get_audio_to_vibe_control(&mut self) -> Result<(AthFilter, AthPeakTime), Error<E>> {
+        self.ensure_rom_library()?;
+
+        let reg = self.device.audio_to_vibe_control().read()?;
+        Ok((reg.ath_filter(), reg.ath_peak_time()))
+    }
+
     /// Set audio-to-vibe minimum input level
     ///
     /// Sets the minimum input level for audio-to-haptic conversion.
     pub fn set_audio_to_vibe_min_input_level(&mut self, level: u8) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         self.device
             .audio_to_vibe_min_input_level()
             .write(|reg| reg.set_ath_min_input(level))?;
@@ -343,6 +1700,8 @@ where
     ///
     /// Sets the maximum input level for audio-to-haptic conversion.
     pub fn set_audio_to_vibe_max_input_level(&mut self, level: u8) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         self.device
             .audio_to_vibe_max_input_level()
             .write(|reg| reg.set_ath_max_input(level))?;
@@ -353,6 +1712,8 @@ where
     ///
     /// Sets the minimum output drive level for audio-to-haptic conversion.
     pub fn set_audio_to_vibe_min_output_drive(&mut self, level: u8) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         self.device
             .audio_to_vibe_min_output_drive()
             .write(|reg| reg.set_ath_min_drive(level))?;
@@ -363,9 +1724,599 @@ where
     ///
     /// Sets the maximum output drive level for audio-to-haptic conversion.
     pub fn set_audio_to_vibe_max_output_drive(&mut self, level: u8) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
         self.device
             .audio_to_vibe_max_output_drive()
             .write(|reg| reg.set_ath_max_drive(level))?;
         Ok(())
     }
+
+    /// Configure audio-to-vibe in one call and enter audio-to-vibe mode
+    ///
+    /// Writes the filter/peak time and the four level/drive registers, then enables AC
+    /// coupling and analog input as required by the datasheet, and switches the device into
+    /// [`OperatingMode::AudioToVibe`].
+    pub fn configure_audio_to_vibe(&mut self, cfg: &AudioToVibeConfig) -> Result<(), Error<E>> {
+        self.ensure_rom_library()?;
+
+        self.device.audio_to_vibe_control().write(|reg| {
+            reg.set_ath_filter(cfg.filter);
+            reg.set_ath_peak_time(cfg.peak_time);
+        })?;
+        self.device
+            .audio_to_vibe_min_input_level()
+            .write(|reg| reg.set_ath_min_input(cfg.min_input_level))?;
+        self.device
+            .audio_to_vibe_max_input_level()
+            .write(|reg| reg.set_ath_max_input(cfg.max_input_level))?;
+        self.device
+            .audio_to_vibe_min_output_drive()
+            .write(|reg| reg.set_ath_min_drive(cfg.min_output_drive))?;
+        self.device
+            .audio_to_vibe_max_output_drive()
+            .write(|reg| reg.set_ath_max_drive(cfg.max_output_drive))?;
+
+        self.device
+            .control_1()
+            .modify(|reg| reg.set_ac_couple(true))?;
+        self.device
+            .control_3()
+            .modify(|reg| reg.set_n_pwm_analog(true))?;
+
+        self.set_mode(OperatingMode::AudioToVibe)
+    }
+
+    /// Read back the audio-to-vibe configuration
+    ///
+    /// Reads the control register (filter and peak time) and the four level/drive registers.
+    pub fn get_audio_to_vibe_config(&mut self) -> Result<AudioToVibeConfig, Error<E>> {
+        self.ensure_rom_library()?;
+
+        let control = self.device.audio_to_vibe_control().read()?;
+        let min_input = self.device.audio_to_vibe_min_input_level().read()?;
+        let max_input = self.device.audio_to_vibe_max_input_level().read()?;
+        let min_drive = self.device.audio_to_vibe_min_output_drive().read()?;
+        let max_drive = self.device.audio_to_vibe_max_output_drive().read()?;
+
+        Ok(AudioToVibeConfig {
+            filter: control.ath_filter(),
+            peak_time: control.ath_peak_time(),
+            min_input_level: min_input.ath_min_input(),
+            max_input_level: max_input.ath_max_input(),
+            min_output_drive: min_drive.ath_min_drive(),
+            max_output_drive: max_drive.ath_max_drive(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::I2C_ADDRESS;
+    use embedded_hal_mock::eh1::delay::{CheckedDelay, NoopDelay, Transaction as DelayTransaction};
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+    /// Expect a read of `value` from `register`
+    fn read(register: u8, value: u8) -> I2cTransaction {
+        I2cTransaction::write_read(I2C_ADDRESS, vec![register], vec![value])
+    }
+
+    /// Expect a write of `data` starting at `register`
+    fn write(register: u8, data: &[u8]) -> I2cTransaction {
+        let mut bytes = vec![register];
+        bytes.extend_from_slice(data);
+        I2cTransaction::write(I2C_ADDRESS, bytes)
+    }
+
+    #[test]
+    fn check_stream_rtp_ends_with_idle_input(control3: u8, idle: u8) {
+        let expectations = [
+            read(0x01, 0x00),
+            write(0x01, &[0x05]),
+            write(0x02, &[0x40]),
+            write(0x02, &[0x7F]),
+            read(0x1D, control3),
+            write(0x02, &[idle]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+        let mut delay = CheckedDelay::new(&[
+            DelayTransaction::delay_us(200),
+            DelayTransaction::delay_us(200),
+        ]);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic.stream_rtp([0x40, 0x7F], &mut delay, 200).unwrap();
+
+        i2c.done();
+        delay.done();
+    }
+
+    #[test]
+    fn stream_rtp_ends_with_zero_in_signed_format() {
+        // DATA_FORMAT_RTP (bit 3) clear: 0x00 is no drive
+        check_stream_rtp_ends_with_idle_input(0xA0, 0x00);
+    }
+
+    #[test]
+    fn stream_rtp_ends_with_mid_scale_in_unsigned_format() {
+        // DATA_FORMAT_RTP (bit 3) set: 0x00 would be full reverse, 0x80 is no drive
+        check_stream_rtp_ends_with_idle_input(0xA8, 0x80);
+    }
+
+    #[test]
+    fn play_sequence_stops_chunk_that_never_finishes() {
+        let entries = [WaveformEntry::wait(1)];
+        let timeout_us = crate::sequence_timeout_us(&entries);
+        let polls = timeout_us.div_ceil(PLAYBACK_POLL_INTERVAL_MS * 1000) + 1;
+
+        let mut expectations = vec![
+            write(0x04, &[0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            write(0x0C, &[0x01]),
+        ];
+        // GO never clears
+        expectations.extend((0..polls).map(|_| read(0x0C, 0x01)));
+        expectations.push(write(0x0C, &[0x00]));
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        let result = haptic.play_sequence(&entries, &mut NoopDelay::new());
+        assert!(matches!(result, Err(Error::Timeout)));
+
+        i2c.done();
+    }
+
+    #[test]
+    fn play_repeating_times_out_instead_of_hanging() {
+        let entries = [WaveformEntry::wait(1)];
+        let timeout_us = crate::sequence_timeout_us(&entries);
+        let polls = timeout_us.div_ceil(PLAYBACK_POLL_INTERVAL_MS * 1000) + 1;
+
+        // Only the first repetition is attempted
+        let mut expectations = vec![
+            write(0x04, &[0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            write(0x0C, &[0x01]),
+        ];
+        expectations.extend((0..polls).map(|_| read(0x0C, 0x01)));
+        expectations.push(write(0x0C, &[0x00]));
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        let result = haptic.play_repeating(&entries, 3, &mut NoopDelay::new());
+        assert!(matches!(result, Err(Error::Timeout)));
+
+        i2c.done();
+    }
+
+    #[test]
+    fn set_waveform_sequence_writes_all_slots_in_one_transaction() {
+        // Register address plus 8 slots, unused slots cleared to stop
+        let expectations = [write(
+            0x04,
+            &[0x01, 0x85, 0x2F, 0x00, 0x00, 0x00, 0x00, 0x00],
+        )];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic
+            .set_waveform_sequence(&[
+                WaveformEntry::effect(1),
+                WaveformEntry::wait(5),
+                WaveformEntry::effect(47),
+            ])
+            .unwrap();
+
+        i2c.done();
+    }
+
+    #[test]
+    fn set_timing_offsets_writes_one_block() {
+        let expectations = [write(0x0D, &[0x05, 0xFF, 0x0A, 0x80])];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic.set_timing_offsets(5, -1, 10, -128).unwrap();
+
+        i2c.done();
+    }
+
+    #[test]
+    fn init_reads_status_then_writes_mode_once() {
+        // Two transactions; the previous init took five (status read, then separate
+        // read-modify-writes of MODE for standby and for the trigger mode)
+        let expectations = [
+            read(0x00, EXPECTED_DEVICE_ID << 5),
+            // Internal trigger mode with standby cleared
+            write(0x01, &[0x00]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic.init().unwrap();
+        assert_eq!(haptic.current_mode, Some(OperatingMode::Internal));
+
+        i2c.done();
+    }
+
+    #[test]
+    fn init_with_variant_defaults_caps_overdrive_clamp() {
+        let variant = DeviceVariant::from_device_id(EXPECTED_DEVICE_ID);
+        let rated = variant.default_rated_voltage().unwrap();
+        let expectations = [
+            read(0x00, EXPECTED_DEVICE_ID << 5),
+            write(0x01, &[0x00]),
+            write(0x16, &[rated]),
+            write(0x17, &[0x89]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic.init_with_variant_defaults().unwrap();
+
+        i2c.done();
+    }
+
+    #[test]
+    fn init_stops_after_wrong_device_id() {
+        let found = (EXPECTED_DEVICE_ID + 1) & 0x07;
+        let expectations = [read(0x00, found << 5)];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        assert!(matches!(
+            haptic.init(),
+            Err(Error::InvalidDeviceId { expected, found: f })
+                if expected == EXPECTED_DEVICE_ID && f == found
+        ));
+
+        i2c.done();
+    }
+
+    #[cfg(all(feature = "otp", any(feature = "drv2605l", feature = "drv2604l")))]
+    #[test]
+    fn program_otp_checks_status_before_and_after_programming() {
+        let expectations = [
+            read(0x1E, 0x20),
+            write(0x1E, &[0x21]),
+            // OTP_STATUS set once programming completes
+            read(0x1E, 0x24),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+        let mut delay = CheckedDelay::new(&[DelayTransaction::delay_ms(OTP_PROGRAM_TIME_MS)]);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic.program_otp(&mut delay).unwrap();
+
+        i2c.done();
+        delay.done();
+    }
+
+    #[cfg(all(feature = "otp", any(feature = "drv2605l", feature = "drv2604l")))]
+    #[test]
+    fn program_otp_refuses_programmed_memory() {
+        let expectations = [read(0x1E, 0x24)];
+        let mut i2c = I2cMock::new(&expectations);
+        let mut delay = CheckedDelay::new(&[]);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        assert!(matches!(
+            haptic.program_otp(&mut delay),
+            Err(Error::InvalidConfig(_))
+        ));
+
+        i2c.done();
+        delay.done();
+    }
+
+    #[test]
+    fn arm_external_trigger_loads_entry_then_sets_mode() {
+        let expectations = [
+            write(0x04, &[0x2F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            read(0x01, 0x00),
+            write(0x01, &[0x02]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic
+            .arm_external_trigger(WaveformEntry::effect(47), true)
+            .unwrap();
+
+        i2c.done();
+    }
+
+    #[test]
+    fn arm_external_trigger_rejects_stop_entry() {
+        let mut i2c = I2cMock::new(&[]);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        assert!(matches!(
+            haptic.arm_external_trigger(WaveformEntry::stop(), false),
+            Err(Error::InvalidWaveform)
+        ));
+
+        i2c.done();
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn play_effect_once_restores_mode_when_stop_fails() {
+        let timeout_us = playback_timeout_us(Effect::StrongClick100.duration_ms());
+        let mut expectations = vec![
+            // Previous mode is RTP
+            read(0x01, 0x05),
+            read(0x01, 0x05),
+            write(0x01, &[0x00]),
+            write(0x04, &[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            write(0x0C, &[0x01]),
+        ];
+        // GO never clears
+        let polls = timeout_us.div_ceil(PLAYBACK_POLL_INTERVAL_MS * 1000) + 1;
+        for _ in 0..polls {
+            expectations.push(read(0x0C, 0x01));
+        }
+        expectations.push(write(0x0C, &[0x00]).with_error(embedded_hal::i2c::ErrorKind::Other));
+        expectations.push(read(0x01, 0x00));
+        expectations.push(write(0x01, &[0x05]));
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        let result = haptic.play_effect_once(Effect::StrongClick100, &mut NoopDelay::new());
+        assert!(matches!(result, Err(Error::Timeout)));
+
+        i2c.done();
+    }
+
+    #[test]
+    fn set_waveform_sequence_keeps_entries_after_early_stop() {
+        let expectations = [write(0x04, &[0x01, 0x00, 0x02, 0, 0, 0, 0, 0])];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic
+            .set_waveform_sequence(&[
+                WaveformEntry::effect(1),
+                WaveformEntry::stop(),
+                WaveformEntry::effect(2),
+            ])
+            .unwrap();
+
+        i2c.done();
+    }
+
+    #[test]
+    fn get_feedback_control_decodes_actuator() {
+        let expectations = [read(0x1A, 0xB6), read(0x1A, 0x36)];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        assert_eq!(
+            haptic.get_feedback_control().unwrap(),
+            FeedbackControl {
+                loop_gain: LoopGain::Medium,
+                brake_factor: FbBrakeFactor::X4,
+                bemf_gain: BemfGain::High,
+                actuator: Actuator::Lra,
+            }
+        );
+        assert_eq!(
+            haptic.get_feedback_control().unwrap().actuator,
+            Actuator::Erm
+        );
+
+        i2c.done();
+    }
+
+    #[test]
+    fn set_feedback_control_masks_raw_bemf_gain() {
+        let expectations = [read(0x1A, 0xB6), write(0x1A, &[0x89])];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic
+            .set_feedback_control(LoopGain::High, FbBrakeFactor::X1, 0x05)
+            .unwrap();
+
+        i2c.done();
+    }
+
+    #[test]
+    fn set_feedback_control_checked_rejects_other_actuator() {
+        // Device set up for an ERM; nothing is written
+        let expectations = [read(0x1A, 0x36)];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        let result = haptic.set_feedback_control_checked(
+            Actuator::Lra,
+            LoopGain::High,
+            FbBrakeFactor::X3,
+            BemfGain::High,
+        );
+        assert!(matches!(result, Err(Error::InvalidConfig(_))));
+
+        i2c.done();
+    }
+
+    #[test]
+    fn set_feedback_control_checked_writes_matching_actuator() {
+        // LRA bit kept, loop gain High, brake X3, BEMF gain High
+        let expectations = [read(0x1A, 0xB6), write(0x1A, &[0xAA])];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic
+            .set_feedback_control_checked(
+                Actuator::Lra,
+                LoopGain::High,
+                FbBrakeFactor::X3,
+                BemfGain::High,
+            )
+            .unwrap();
+
+        i2c.done();
+    }
+
+    #[test]
+    fn set_pwm_analog_input_toggles_only_n_pwm_analog() {
+        let expectations = [
+            read(0x1D, 0xA0),
+            write(0x1D, &[0xA2]),
+            read(0x1D, 0xFF),
+            write(0x1D, &[0xFD]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic.set_pwm_analog_input(true).unwrap();
+        haptic.set_pwm_analog_input(false).unwrap();
+
+        i2c.done();
+    }
+
+    /// Expect `set_loop_mode` to turn CONTROL3 `before` into `after`
+    fn check_set_loop_mode(actuator: Actuator, open_loop: bool, before: u8, after: u8) {
+        let expectations = [read(0x1D, before), write(0x1D, &[after])];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic.set_loop_mode(actuator, open_loop).unwrap();
+
+        i2c.done();
+    }
+
+    #[test]
+    fn set_loop_mode_erm_open_sets_erm_open_loop() {
+        check_set_loop_mode(Actuator::Erm, true, 0x80, 0xA0);
+    }
+
+    #[test]
+    fn set_loop_mode_erm_closed_clears_erm_open_loop() {
+        check_set_loop_mode(Actuator::Erm, false, 0xFF, 0xDF);
+    }
+
+    #[test]
+    fn set_loop_mode_lra_open_sets_lra_open_loop() {
+        check_set_loop_mode(Actuator::Lra, true, 0xA0, 0xA1);
+    }
+
+    #[test]
+    fn set_loop_mode_lra_closed_clears_lra_open_loop() {
+        check_set_loop_mode(Actuator::Lra, false, 0xFF, 0xFE);
+    }
+
+    #[test]
+    fn get_loop_mode_reads_the_actuator_bit() {
+        let expectations = [read(0x1D, 0x20), read(0x1D, 0x20)];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        assert!(haptic.get_loop_mode(Actuator::Erm).unwrap());
+        assert!(!haptic.get_loop_mode(Actuator::Lra).unwrap());
+
+        i2c.done();
+    }
+
+    #[test]
+    fn set_startup_boost_toggles_only_startup_boost() {
+        let expectations = [
+            read(0x1B, 0x13),
+            write(0x1B, &[0x93]),
+            read(0x1B, 0x93),
+            write(0x1B, &[0x13]),
+            read(0x1B, 0x93),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic.set_startup_boost(true).unwrap();
+        haptic.set_startup_boost(false).unwrap();
+        assert!(haptic.get_startup_boost().unwrap());
+
+        i2c.done();
+    }
+
+    #[cfg(any(feature = "drv2604l", feature = "drv2605l"))]
+    #[test]
+    fn set_lra_open_loop_resonance_changes_only_auto_open_loop_fields() {
+        let expectations = [
+            // Enable with 6 attempts: LRA_AUTO_OPEN_LOOP and AUTO_OL_CNT change
+            read(0x1F, 0x9F),
+            write(0x1F, &[0xFF]),
+            // Disable: only LRA_AUTO_OPEN_LOOP changes, AUTO_OL_CNT is kept
+            read(0x1F, 0xFF),
+            write(0x1F, &[0xDF]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        haptic
+            .set_lra_open_loop_resonance(Some(AutoOpenLoopCnt::X6))
+            .unwrap();
+        haptic.set_lra_open_loop_resonance(None).unwrap();
+
+        i2c.done();
+    }
+
+    #[cfg(any(feature = "drv2604l", feature = "drv2605l"))]
+    #[test]
+    fn get_lra_open_loop_resonance_decodes_attempts() {
+        let expectations = [read(0x1F, 0x60), read(0x1F, 0xC0)];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        assert_eq!(
+            haptic.get_lra_open_loop_resonance().unwrap(),
+            Some(AutoOpenLoopCnt::X4)
+        );
+        assert_eq!(haptic.get_lra_open_loop_resonance().unwrap(), None);
+
+        i2c.done();
+    }
+
+    #[test]
+    fn ensure_mode_skips_write_when_mode_is_cached() {
+        let expectations = [
+            // Unknown mode: always written
+            read(0x01, 0x00),
+            write(0x01, &[0x00]),
+            // Different mode: written
+            read(0x01, 0x00),
+            write(0x01, &[0x05]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        assert!(haptic.ensure_mode(OperatingMode::Internal).unwrap());
+        assert!(!haptic.ensure_mode(OperatingMode::Internal).unwrap());
+        assert!(haptic.ensure_mode(OperatingMode::Playback).unwrap());
+        assert!(!haptic.ensure_mode(OperatingMode::Playback).unwrap());
+
+        i2c.done();
+    }
+
+    #[cfg(any(feature = "drv2605", feature = "drv2605l"))]
+    #[test]
+    fn play_effect_tuned_sets_brake_factor_before_playback() {
+        let expectations = [
+            // FB_BRAKE_FACTOR 1x -> 4x, other feedback bits kept
+            read(0x1A, 0x86),
+            write(0x1A, &[0xB6]),
+            write(0x04, &[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            write(0x0C, &[0x01]),
+            // No recommendation for buzzes: feedback control is not touched
+            write(0x04, &[0x2F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            write(0x0C, &[0x01]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut haptic = Drv260x::new(i2c.clone());
+        assert_eq!(
+            haptic.play_effect_tuned(Effect::StrongClick100).unwrap(),
+            Effect::StrongClick100.duration_ms()
+        );
+        haptic.play_effect_tuned(Effect::Buzz1_100).unwrap();
+
+        i2c.done();
+    }
 }
@@ -0,0 +1,31 @@
+//! Driver use on an I2C bus shared through `embedded-hal-bus`
+
+use core::cell::RefCell;
+
+use drv260x::{Drv260x, I2C_ADDRESS};
+use embedded_hal::i2c::I2c;
+use embedded_hal_bus::i2c::RefCellDevice;
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+/// Address of another device on the same bus
+const OTHER_ADDRESS: u8 = 0x48;
+
+#[test]
+fn driver_interleaves_with_other_device_on_refcell_bus() {
+    let expectations = [
+        I2cTransaction::write(I2C_ADDRESS, vec![0x02, 0x40]),
+        I2cTransaction::write(OTHER_ADDRESS, vec![0x01, 0xFF]),
+        I2cTransaction::write(I2C_ADDRESS, vec![0x0C, 0x00]),
+    ];
+    let bus = RefCell::new(I2cMock::new(&expectations));
+
+    let mut haptic = Drv260x::on_shared_bus(RefCellDevice::new(&bus));
+    let mut other = RefCellDevice::new(&bus);
+
+    haptic.set_rtp_input(0x40).unwrap();
+    other.write(OTHER_ADDRESS, &[0x01, 0xFF]).unwrap();
+    haptic.stop().unwrap();
+
+    drop(haptic);
+    bus.into_inner().done();
+}